@@ -3,7 +3,7 @@
 use anchor_lang::prelude::*;
 
 use crate::errors::SolscopeError;
-use crate::state::BotMeta;
+use crate::state::{BotAction, BotMeta};
 
 #[derive(Accounts)]
 #[instruction(bot_id_hash: [u8; 32])]
@@ -43,8 +43,8 @@ pub fn handler(ctx: Context<AssertVault>, bot_id_hash: [u8; 32]) -> Result<()> {
         SolscopeError::BotIdMismatch
     );
 
-    // emergency pause
-    require!(!ctx.accounts.bot_meta.paused, SolscopeError::BotPaused);
+    // lifecycle status + emergency pause
+    ctx.accounts.bot_meta.require_allows(BotAction::Assert)?;
 
     // optional: ensure stored bump matches derived bump
     require!(
@@ -0,0 +1,126 @@
+// programs/solscope/src/cancel_limit_order.rs
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program::invoke_signed;
+
+use crate::{
+    errors::SolscopeError,
+    guards,
+    openbook_v2::{self, openbook_v2_program, MarketHeader},
+    state::{BotAction, BotMeta, OpenOrdersRecord, SessionKey},
+};
+
+#[derive(Accounts)]
+#[instruction(bot_id_hash: [u8; 32])]
+pub struct CancelLimitOrder<'info> {
+    /// Owner, delegated operator or live session key
+    pub authority: Signer<'info>,
+
+    /// BotMeta PDA (derived from the stored owner, not the signer)
+    #[account(
+        seeds = [b"bot", bot_meta.owner.as_ref(), &bot_id_hash],
+        bump,
+        has_one = vault
+    )]
+    pub bot_meta: Account<'info, BotMeta>,
+
+    /// CHECK: Vault PDA (open-orders owner)
+    #[account(
+        seeds = [b"vault", bot_meta.owner.as_ref(), &bot_id_hash],
+        bump
+    )]
+    pub vault: AccountInfo<'info>,
+
+    /// SessionKey PDA (required only when authority is a session key)
+    #[account(
+        mut,
+        seeds = [b"session", bot_meta.key().as_ref(), authority.key().as_ref()],
+        bump = session_key.bump,
+        has_one = bot_meta,
+    )]
+    pub session_key: Option<Account<'info, SessionKey>>,
+
+    /// OpenOrdersRecord PDA
+    #[account(
+        seeds = [b"open_orders", bot_meta.key().as_ref()],
+        bump = open_orders_record.bump,
+        has_one = bot_meta,
+    )]
+    pub open_orders_record: Account<'info, OpenOrdersRecord>,
+
+    /* ========== OpenBook v2 Accounts ========== */
+    /// CHECK: OpenBook v2 market (owner + discriminator checked in handler)
+    pub market: AccountInfo<'info>,
+
+    /// CHECK: Vault's open-orders account recorded for `market`
+    #[account(
+        mut,
+        constraint = open_orders_record.open_orders_for(market.key)
+            == Some(open_orders_account.key()) @ SolscopeError::OpenOrdersNotFound
+    )]
+    pub open_orders_account: AccountInfo<'info>,
+
+    /// CHECK: Market bids (checked against the market)
+    #[account(mut)]
+    pub bids: AccountInfo<'info>,
+    /// CHECK: Market asks (checked against the market)
+    #[account(mut)]
+    pub asks: AccountInfo<'info>,
+
+    /// CHECK: OpenBook v2 program
+    #[account(address = openbook_v2_program())]
+    pub openbook_program: AccountInfo<'info>,
+}
+
+/// Pull a resting order by the client id it was placed with
+pub fn handler(
+    ctx: Context<CancelLimitOrder>,
+    bot_id_hash: [u8; 32],
+    client_order_id: u64,
+) -> Result<()> {
+    require!(
+        ctx.accounts.bot_meta.bot_id_hash == bot_id_hash,
+        SolscopeError::BotIdMismatch
+    );
+    // cancelling only reduces exposure, so it stays open in every status but Closed
+    ctx.accounts.bot_meta.require_allows(BotAction::Settle)?;
+
+    /* ================= Signer authorization ================= */
+    guards::authorize_trader(
+        &ctx.accounts.bot_meta,
+        ctx.accounts.authority.key,
+        ctx.accounts.session_key.as_mut(),
+        None,
+    )?;
+
+    /* ================= Market validation ================= */
+    let program = ctx.accounts.openbook_program.key();
+    let market = *MarketHeader::load(&ctx.accounts.market, &program)?;
+    require!(
+        market.bids == ctx.accounts.bids.key() && market.asks == ctx.accounts.asks.key(),
+        SolscopeError::InvalidPoolAccount
+    );
+
+    /* ================= PDA signer ================= */
+    let owner = ctx.accounts.bot_meta.owner;
+    let vault_seeds: &[&[u8]] = &[
+        b"vault",
+        owner.as_ref(),
+        &bot_id_hash,
+        &[ctx.bumps.vault],
+    ];
+    let signer_seeds = &[vault_seeds];
+
+    /* ================= OpenBook cancel ================= */
+    let ix = openbook_v2::cancel_order_by_client_order_id_ix(
+        &program,
+        ctx.accounts.market.key,
+        &market,
+        ctx.accounts.vault.key,
+        ctx.accounts.open_orders_account.key,
+        client_order_id,
+    );
+
+    invoke_signed(&ix, &ctx.accounts.to_account_infos(), signer_seeds)?;
+    Ok(())
+}
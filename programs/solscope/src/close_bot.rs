@@ -0,0 +1,212 @@
+// programs/solscope/src/close_bot.rs
+
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use anchor_spl::token::{self, CloseAccount, Token, TokenAccount};
+
+use crate::errors::SolscopeError;
+use crate::state::{
+    BotAction, BotMeta, MintPolicy, OpenOrdersRecord, OracleConfig, PoolAllowlist, RiskConfig,
+    SessionKey,
+};
+
+#[derive(Accounts)]
+#[instruction(bot_id_hash: [u8; 32])]
+pub struct CloseBot<'info> {
+    /// Bot owner (receives all reclaimed rent + vault lamports)
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    /// BotMeta PDA (closed to owner)
+    #[account(
+        mut,
+        close = owner,
+        seeds = [
+            b"bot",
+            owner.key().as_ref(),
+            &bot_id_hash,
+        ],
+        bump,
+        has_one = owner,
+        has_one = vault
+    )]
+    pub bot_meta: Account<'info, BotMeta>,
+
+    /// Vault PDA (lamports-only system account)
+    #[account(
+        mut,
+        seeds = [
+            b"vault",
+            owner.key().as_ref(),
+            &bot_id_hash,
+        ],
+        bump
+    )]
+    pub vault: SystemAccount<'info>,
+
+    /// RiskConfig PDA (required if the bot has one; closed to owner)
+    #[account(
+        mut,
+        close = owner,
+        seeds = [b"risk", bot_meta.key().as_ref()],
+        bump = risk_config.bump,
+        has_one = bot_meta,
+    )]
+    pub risk_config: Option<Account<'info, RiskConfig>>,
+
+    /// MintPolicy PDA (required if the bot has one; closed to owner)
+    #[account(
+        mut,
+        close = owner,
+        seeds = [b"mint_policy", bot_meta.key().as_ref()],
+        bump = mint_policy.bump,
+        has_one = bot_meta,
+    )]
+    pub mint_policy: Option<Account<'info, MintPolicy>>,
+
+    /// PoolAllowlist PDA (required if the bot has one; closed to owner)
+    #[account(
+        mut,
+        close = owner,
+        seeds = [b"pools", bot_meta.key().as_ref()],
+        bump = pool_allowlist.bump,
+        has_one = bot_meta,
+    )]
+    pub pool_allowlist: Option<Account<'info, PoolAllowlist>>,
+
+    /// OracleConfig PDA (required if the bot has one; closed to owner)
+    #[account(
+        mut,
+        close = owner,
+        seeds = [b"oracle", bot_meta.key().as_ref()],
+        bump = oracle_config.bump,
+        has_one = bot_meta,
+    )]
+    pub oracle_config: Option<Account<'info, OracleConfig>>,
+
+    /// OpenOrdersRecord PDA (required if the bot has one; must be empty; closed to owner)
+    #[account(
+        mut,
+        close = owner,
+        seeds = [b"open_orders", bot_meta.key().as_ref()],
+        bump = open_orders_record.bump,
+        has_one = bot_meta,
+    )]
+    pub open_orders_record: Option<Account<'info, OpenOrdersRecord>>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+    // remaining_accounts (writable):
+    //   every vault ATA (must be empty)
+    //   every live SessionKey PDA of this bot
+}
+
+pub fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, CloseBot<'info>>,
+    bot_id_hash: [u8; 32],
+) -> Result<()> {
+    require!(
+        ctx.accounts.bot_meta.bot_id_hash == bot_id_hash,
+        SolscopeError::BotIdMismatch
+    );
+
+    // retiring is only allowed from Paused or Closing
+    ctx.accounts.bot_meta.require_allows(BotAction::Close)?;
+
+    /* ================= Per-bot PDAs (closed to owner by their constraints) ================= */
+    // nothing may outlive BotMeta, or a re-registered bot id would collide with it
+    let bot_meta = &ctx.accounts.bot_meta;
+    require!(
+        bot_meta.risk_config.is_none() || ctx.accounts.risk_config.is_some(),
+        SolscopeError::RiskConfigMissing
+    );
+    require!(
+        bot_meta.mint_policy.is_none() || ctx.accounts.mint_policy.is_some(),
+        SolscopeError::MintPolicyMissing
+    );
+    require!(
+        bot_meta.pool_allowlist.is_none() || ctx.accounts.pool_allowlist.is_some(),
+        SolscopeError::PoolAllowlistMissing
+    );
+    require!(
+        bot_meta.oracle_config.is_none() || ctx.accounts.oracle_config.is_some(),
+        SolscopeError::OracleConfigMissing
+    );
+    require!(
+        bot_meta.open_orders_record.is_none() || ctx.accounts.open_orders_record.is_some(),
+        SolscopeError::OpenOrdersRecordMissing
+    );
+    // open-orders accounts are owned by the vault; close_open_orders them first
+    if let Some(record) = &ctx.accounts.open_orders_record {
+        require!(record.entries.is_empty(), SolscopeError::OpenOrdersRecordNotEmpty);
+    }
+
+    let seeds: &[&[u8]] = &[
+        b"vault",
+        ctx.accounts.owner.key.as_ref(),
+        &bot_id_hash,
+        &[ctx.bumps.vault],
+    ];
+    let signer_seeds = &[seeds];
+
+    /* ================= Close vault ATAs + session keys (rent -> owner) ================= */
+    let mut closed_sessions: u16 = 0;
+    for ata in ctx.remaining_accounts.iter() {
+        if ata.owner == &crate::ID {
+            let session_key = Account::<SessionKey>::try_from(ata)?;
+            require!(
+                session_key.bot_meta == ctx.accounts.bot_meta.key(),
+                SolscopeError::Unauthorized
+            );
+            session_key.close(ctx.accounts.owner.to_account_info())?;
+            closed_sessions = closed_sessions.saturating_add(1);
+            continue;
+        }
+
+        require!(ata.owner == &token::ID, SolscopeError::InvalidVault);
+
+        let token_account = TokenAccount::try_deserialize(&mut &ata.data.borrow()[..])?;
+        require!(
+            token_account.owner == ctx.accounts.vault.key(),
+            SolscopeError::InvalidVault
+        );
+        require!(
+            token_account.amount == 0,
+            SolscopeError::VaultTokenAccountNotEmpty
+        );
+
+        token::close_account(CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            CloseAccount {
+                account: ata.clone(),
+                destination: ctx.accounts.owner.to_account_info(),
+                authority: ctx.accounts.vault.to_account_info(),
+            },
+            signer_seeds,
+        ))?;
+    }
+
+    require!(
+        closed_sessions >= ctx.accounts.bot_meta.session_keys,
+        SolscopeError::SessionKeysOpen
+    );
+
+    /* ================= Sweep vault lamports ================= */
+    let vault_lamports = ctx.accounts.vault.lamports();
+    if vault_lamports > 0 {
+        system_program::transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: ctx.accounts.vault.to_account_info(),
+                    to: ctx.accounts.owner.to_account_info(),
+                },
+                signer_seeds,
+            ),
+            vault_lamports,
+        )?;
+    }
+
+    // BotMeta itself is closed by the `close = owner` constraint
+    Ok(())
+}
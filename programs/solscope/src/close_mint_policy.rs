@@ -0,0 +1,36 @@
+// programs/solscope/src/close_mint_policy.rs
+
+use anchor_lang::prelude::*;
+
+use crate::state::{BotMeta, MintPolicy};
+
+#[derive(Accounts)]
+#[instruction(bot_id_hash: [u8; 32])]
+pub struct CloseMintPolicy<'info> {
+    /// Bot owner (receives the MintPolicy rent)
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"bot", owner.key().as_ref(), &bot_id_hash],
+        bump,
+        has_one = owner,
+    )]
+    pub bot_meta: Account<'info, BotMeta>,
+
+    #[account(
+        mut,
+        close = owner,
+        seeds = [b"mint_policy", bot_meta.key().as_ref()],
+        bump = mint_policy.bump,
+        has_one = bot_meta,
+    )]
+    pub mint_policy: Account<'info, MintPolicy>,
+}
+
+pub fn handler(ctx: Context<CloseMintPolicy>, _bot_id_hash: [u8; 32]) -> Result<()> {
+    // owner-only enforced by has_one + owner signer; init_mint_policy can run again after this
+    ctx.accounts.bot_meta.mint_policy = None;
+    Ok(())
+}
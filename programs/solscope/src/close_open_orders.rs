@@ -0,0 +1,136 @@
+// programs/solscope/src/close_open_orders.rs
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program::invoke_signed;
+
+use crate::{
+    errors::SolscopeError,
+    guards,
+    openbook_v2::{self, openbook_v2_program},
+    state::{BotAction, BotMeta, OpenOrdersRecord, SessionKey},
+};
+
+#[derive(Accounts)]
+#[instruction(bot_id_hash: [u8; 32])]
+pub struct CloseOpenOrders<'info> {
+    /// Owner, delegated operator or live session key
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// BotMeta PDA (derived from the stored owner, not the signer)
+    #[account(
+        seeds = [b"bot", bot_meta.owner.as_ref(), &bot_id_hash],
+        bump,
+        has_one = vault
+    )]
+    pub bot_meta: Account<'info, BotMeta>,
+
+    /// CHECK: Vault PDA (open-orders owner)
+    #[account(
+        seeds = [b"vault", bot_meta.owner.as_ref(), &bot_id_hash],
+        bump
+    )]
+    pub vault: AccountInfo<'info>,
+
+    /// SessionKey PDA (required only when authority is a session key)
+    #[account(
+        mut,
+        seeds = [b"session", bot_meta.key().as_ref(), authority.key().as_ref()],
+        bump = session_key.bump,
+        has_one = bot_meta,
+    )]
+    pub session_key: Option<Account<'info, SessionKey>>,
+
+    /// OpenOrdersRecord PDA (entry for `market` is removed)
+    #[account(
+        mut,
+        seeds = [b"open_orders", bot_meta.key().as_ref()],
+        bump = open_orders_record.bump,
+        has_one = bot_meta,
+    )]
+    pub open_orders_record: Account<'info, OpenOrdersRecord>,
+
+    /// CHECK: Market the open-orders account was recorded for (key lookup only)
+    pub market: AccountInfo<'info>,
+
+    /// CHECK: Vault's OpenOrdersIndexer PDA
+    #[account(mut)]
+    pub open_orders_indexer: AccountInfo<'info>,
+
+    /// CHECK: Vault's open-orders account recorded for `market`
+    #[account(
+        mut,
+        constraint = open_orders_record.open_orders_for(market.key)
+            == Some(open_orders_account.key()) @ SolscopeError::OpenOrdersNotFound
+    )]
+    pub open_orders_account: AccountInfo<'info>,
+
+    /// CHECK: Bot owner (receives the open-orders rent)
+    #[account(mut, address = bot_meta.owner @ SolscopeError::Unauthorized)]
+    pub owner: AccountInfo<'info>,
+
+    /// CHECK: OpenBook v2 program
+    #[account(address = openbook_v2_program())]
+    pub openbook_program: AccountInfo<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Close the vault's (empty) open-orders account for a market and drop it from the record
+pub fn handler(ctx: Context<CloseOpenOrders>, bot_id_hash: [u8; 32]) -> Result<()> {
+    require!(
+        ctx.accounts.bot_meta.bot_id_hash == bot_id_hash,
+        SolscopeError::BotIdMismatch
+    );
+    // closing only unwinds, so it stays open in every status but Closed
+    ctx.accounts.bot_meta.require_allows(BotAction::Settle)?;
+
+    /* ================= Signer authorization ================= */
+    guards::authorize_trader(
+        &ctx.accounts.bot_meta,
+        ctx.accounts.authority.key,
+        ctx.accounts.session_key.as_mut(),
+        None,
+    )?;
+
+    let program = ctx.accounts.openbook_program.key();
+    let vault_key = ctx.accounts.vault.key();
+    let indexer_key = ctx.accounts.open_orders_indexer.key();
+    require!(
+        openbook_v2::open_orders_indexer(&vault_key, &program) == indexer_key,
+        SolscopeError::InvalidPoolAccount
+    );
+
+    /* ================= PDA signer ================= */
+    let owner = ctx.accounts.bot_meta.owner;
+    let vault_seeds: &[&[u8]] = &[
+        b"vault",
+        owner.as_ref(),
+        &bot_id_hash,
+        &[ctx.bumps.vault],
+    ];
+    let signer_seeds = &[vault_seeds];
+
+    /* ================= OpenBook close (fails while orders or funds remain) ================= */
+    let ix = openbook_v2::close_open_orders_account_ix(
+        &program,
+        ctx.accounts.authority.key,
+        &vault_key,
+        &indexer_key,
+        ctx.accounts.open_orders_account.key,
+        ctx.accounts.owner.key,
+    );
+    let infos = [
+        ctx.accounts.authority.to_account_info(),
+        ctx.accounts.vault.to_account_info(),
+        ctx.accounts.open_orders_indexer.to_account_info(),
+        ctx.accounts.open_orders_account.to_account_info(),
+        ctx.accounts.owner.to_account_info(),
+        ctx.accounts.openbook_program.to_account_info(),
+        ctx.accounts.system_program.to_account_info(),
+    ];
+    invoke_signed(&ix, &infos, signer_seeds)?;
+
+    ctx.accounts.open_orders_record.remove(ctx.accounts.market.key);
+    Ok(())
+}
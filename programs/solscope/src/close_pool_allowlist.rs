@@ -0,0 +1,36 @@
+// programs/solscope/src/close_pool_allowlist.rs
+
+use anchor_lang::prelude::*;
+
+use crate::state::{BotMeta, PoolAllowlist};
+
+#[derive(Accounts)]
+#[instruction(bot_id_hash: [u8; 32])]
+pub struct ClosePoolAllowlist<'info> {
+    /// Bot owner (receives the PoolAllowlist rent)
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"bot", owner.key().as_ref(), &bot_id_hash],
+        bump,
+        has_one = owner,
+    )]
+    pub bot_meta: Account<'info, BotMeta>,
+
+    #[account(
+        mut,
+        close = owner,
+        seeds = [b"pools", bot_meta.key().as_ref()],
+        bump = pool_allowlist.bump,
+        has_one = bot_meta,
+    )]
+    pub pool_allowlist: Account<'info, PoolAllowlist>,
+}
+
+pub fn handler(ctx: Context<ClosePoolAllowlist>, _bot_id_hash: [u8; 32]) -> Result<()> {
+    // owner-only enforced by has_one + owner signer; init_pool_allowlist can run again after this
+    ctx.accounts.bot_meta.pool_allowlist = None;
+    Ok(())
+}
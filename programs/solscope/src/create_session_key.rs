@@ -0,0 +1,69 @@
+// programs/solscope/src/create_session_key.rs
+
+use anchor_lang::prelude::*;
+
+use crate::errors::SolscopeError;
+use crate::state::{BotMeta, SessionKey};
+
+#[derive(Accounts)]
+#[instruction(bot_id_hash: [u8; 32], session_pubkey: Pubkey)]
+pub struct CreateSessionKey<'info> {
+    /// Bot owner (pays for the SessionKey PDA)
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"bot", owner.key().as_ref(), &bot_id_hash],
+        bump,
+        has_one = owner,
+    )]
+    pub bot_meta: Account<'info, BotMeta>,
+
+    /// SessionKey PDA (1 per session pubkey per bot)
+    #[account(
+        init,
+        payer = owner,
+        space = 8 + SessionKey::LEN,
+        seeds = [b"session", bot_meta.key().as_ref(), session_pubkey.as_ref()],
+        bump
+    )]
+    pub session_key: Account<'info, SessionKey>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(
+    ctx: Context<CreateSessionKey>,
+    _bot_id_hash: [u8; 32],
+    session_pubkey: Pubkey,
+    expires_at: i64,
+    max_lamports_per_trade: u64,
+    budget: u64,
+) -> Result<()> {
+    require!(
+        max_lamports_per_trade > 0 && budget > 0,
+        SolscopeError::InvalidAmount
+    );
+    require!(
+        expires_at > Clock::get()?.unix_timestamp,
+        SolscopeError::SessionKeyExpired
+    );
+
+    let session_key = &mut ctx.accounts.session_key;
+
+    session_key.bot_meta = ctx.accounts.bot_meta.key();
+    session_key.key = session_pubkey;
+    session_key.expires_at = expires_at;
+    session_key.max_lamports_per_trade = max_lamports_per_trade;
+    session_key.remaining_budget = budget;
+    session_key.bump = ctx.bumps.session_key;
+
+    // close_bot must account for every live session key
+    let bot_meta = &mut ctx.accounts.bot_meta;
+    bot_meta.session_keys = bot_meta
+        .session_keys
+        .checked_add(1)
+        .ok_or(SolscopeError::InvalidAmount)?;
+    Ok(())
+}
@@ -0,0 +1,105 @@
+// programs/solscope/src/dex.rs
+
+use std::str::FromStr;
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::Instruction;
+
+use crate::errors::SolscopeError;
+use crate::meteora_dlmm::MeteoraDlmmVenue;
+use crate::orca_whirlpool::OrcaWhirlpoolVenue;
+use crate::raydium::RaydiumPoolAccounts;
+use crate::raydium_clmm::RaydiumClmmVenue;
+use crate::raydium_cpmm::RaydiumCpmmAccounts;
+
+/* ======================================================
+ * Venue ids (the `dex` instruction argument)
+ * ====================================================== */
+pub const DEX_RAYDIUM_V4: u8 = 0;
+pub const DEX_RAYDIUM_CPMM: u8 = 1;
+pub const DEX_RAYDIUM_CLMM: u8 = 2;
+pub const DEX_ORCA_WHIRLPOOL: u8 = 3;
+pub const DEX_METEORA_DLMM: u8 = 4;
+
+/* ======================================================
+ * Program ids shared by several venues
+ * ====================================================== */
+pub fn token_2022_program_id() -> Pubkey {
+    Pubkey::from_str("TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb").unwrap()
+}
+
+pub fn memo_program_id() -> Pubkey {
+    Pubkey::from_str("MemoSq4gqABAXKb96qnH8TysNcWxMyWCqXgDLGmfcHr").unwrap()
+}
+
+/// ExactIn: spend exactly `amount_in`, receive >= `min_out` (Raydium SwapBaseIn)
+/// ExactOut: spend <= `amount_in`, receive >= `min_out` (Raydium SwapBaseOut)
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum SwapMode {
+    ExactIn,
+    ExactOut,
+}
+
+/* ======================================================
+ * DexAdapter
+ * Everything venue-specific about a swap. Trade handlers own the vault,
+ * wSOL wrapping, guards and balance-delta checks; the adapter owns the
+ * venue's accounts, their validation and the CPI instruction.
+ * ====================================================== */
+pub trait DexAdapter<'info> {
+    /// Parse this venue's accounts (in its documented order) off the front of `accounts`
+    fn load(accounts: &mut &'info [AccountInfo<'info>]) -> Result<Self>
+    where
+        Self: Sized;
+
+    /// Program the swap CPI is sent to
+    fn program_id(&self) -> Pubkey;
+
+    /// Pool key checked against the bot's PoolAllowlist
+    fn pool_id(&self) -> Pubkey;
+
+    /// Verify the venue accounts against on-chain state (no fake pools) and
+    /// that this pool swaps `input_mint` into `output_mint`; venues may record
+    /// the swap direction here for `swap_ix`
+    fn validate(&mut self, input_mint: &Pubkey, output_mint: &Pubkey) -> Result<()>;
+
+    /// Swap `user_source` into `user_dest`, signed by `user_owner` (the vault PDA)
+    fn swap_ix(
+        &self,
+        user_source: &Pubkey,
+        user_dest: &Pubkey,
+        user_owner: &Pubkey,
+        mode: SwapMode,
+        amount_in: u64,
+        min_out: u64,
+    ) -> Result<Instruction>;
+
+    /// Venue account infos the CPI needs (user accounts are added by the caller)
+    fn cpi_accounts(&self) -> Vec<AccountInfo<'info>>;
+
+    /// Account credited with the swap output; handlers measure its balance delta
+    fn output_account<'a>(&'a self, user_dest: &'a AccountInfo<'info>) -> &'a AccountInfo<'info> {
+        user_dest
+    }
+}
+
+/// Venues whose loader consumes every remaining account (tick / bin arrays);
+/// they can only be the last hop of a route
+pub fn takes_trailing_accounts(dex: u8) -> bool {
+    matches!(dex, DEX_RAYDIUM_CLMM | DEX_METEORA_DLMM)
+}
+
+/// Load the adapter for `dex`, consuming its accounts from `accounts`
+pub fn load_venue<'info>(
+    dex: u8,
+    accounts: &mut &'info [AccountInfo<'info>],
+) -> Result<Box<dyn DexAdapter<'info> + 'info>> {
+    match dex {
+        DEX_RAYDIUM_V4 => Ok(Box::new(RaydiumPoolAccounts::load(accounts)?)),
+        DEX_RAYDIUM_CPMM => Ok(Box::new(RaydiumCpmmAccounts::load(accounts)?)),
+        DEX_RAYDIUM_CLMM => Ok(Box::new(RaydiumClmmVenue::load(accounts)?)),
+        DEX_ORCA_WHIRLPOOL => Ok(Box::new(OrcaWhirlpoolVenue::load(accounts)?)),
+        DEX_METEORA_DLMM => Ok(Box::new(MeteoraDlmmVenue::load(accounts)?)),
+        _ => err!(SolscopeError::UnknownDex),
+    }
+}
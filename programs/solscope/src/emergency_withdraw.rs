@@ -0,0 +1,104 @@
+// programs/solscope/src/emergency_withdraw.rs
+
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+
+use crate::errors::SolscopeError;
+use crate::events::EmergencyWithdrawal;
+use crate::state::{BotAction, BotMeta};
+
+#[derive(Accounts)]
+#[instruction(bot_id_hash: [u8; 32])]
+pub struct EmergencyWithdraw<'info> {
+    /// Bot owner (must match BotMeta + vault derivation)
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    /// BotMeta PDA (must link owner + vault)
+    #[account(
+        seeds = [
+            b"bot",
+            owner.key().as_ref(),
+            &bot_id_hash,
+        ],
+        bump,
+        has_one = owner,
+        has_one = vault
+    )]
+    pub bot_meta: Account<'info, BotMeta>,
+
+    /// Vault PDA (lamports-only system account)
+    #[account(
+        mut,
+        seeds = [
+            b"vault",
+            owner.key().as_ref(),
+            &bot_id_hash,
+        ],
+        bump
+    )]
+    pub vault: SystemAccount<'info>,
+
+    /// Owner or pre-registered recovery address
+    #[account(
+        mut,
+        constraint = bot_meta.is_recovery_destination(destination.key)
+            @ SolscopeError::InvalidRecoveryDestination
+    )]
+    pub destination: SystemAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(
+    ctx: Context<EmergencyWithdraw>,
+    bot_id_hash: [u8; 32],
+    amount: u64,
+) -> Result<()> {
+    require!(amount > 0, SolscopeError::InvalidAmount);
+
+    // hard gates (inverse of withdraw: only while Paused)
+    require!(
+        ctx.accounts.bot_meta.bot_id_hash == bot_id_hash,
+        SolscopeError::BotIdMismatch
+    );
+    ctx.accounts.bot_meta.require_allows(BotAction::EmergencyWithdraw)?;
+
+    let vault_lamports = ctx.accounts.vault.lamports();
+    require!(vault_lamports >= amount, SolscopeError::InsufficientVaultFunds);
+
+    let remaining = vault_lamports - amount;
+    require!(
+        remaining == 0 || remaining >= Rent::get()?.minimum_balance(0),
+        SolscopeError::VaultBelowRentExempt
+    );
+
+    let seeds: &[&[u8]] = &[
+        b"vault",
+        ctx.accounts.owner.key.as_ref(),
+        &bot_id_hash,
+        &[ctx.bumps.vault],
+    ];
+    let signer_seeds = &[seeds];
+
+    let cpi_ctx = CpiContext::new_with_signer(
+        ctx.accounts.system_program.to_account_info(),
+        system_program::Transfer {
+            from: ctx.accounts.vault.to_account_info(),
+            to: ctx.accounts.destination.to_account_info(),
+        },
+        signer_seeds,
+    );
+
+    system_program::transfer(cpi_ctx, amount)?;
+
+    emit!(EmergencyWithdrawal {
+        bot_meta: ctx.accounts.bot_meta.key(),
+        owner: ctx.accounts.owner.key(),
+        destination: ctx.accounts.destination.key(),
+        amount,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,125 @@
+// programs/solscope/src/emergency_withdraw_token.rs
+
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::{self, Mint, Token, TokenAccount, TransferChecked},
+};
+
+use crate::errors::SolscopeError;
+use crate::events::EmergencyTokenWithdrawal;
+use crate::state::{BotAction, BotMeta};
+
+#[derive(Accounts)]
+#[instruction(bot_id_hash: [u8; 32])]
+pub struct EmergencyWithdrawToken<'info> {
+    /// Bot owner (must match BotMeta + vault derivation; pays for the destination ATA)
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    /// BotMeta PDA (must link owner + vault)
+    #[account(
+        seeds = [
+            b"bot",
+            owner.key().as_ref(),
+            &bot_id_hash,
+        ],
+        bump,
+        has_one = owner,
+        has_one = vault
+    )]
+    pub bot_meta: Account<'info, BotMeta>,
+
+    /// CHECK: Vault PDA (authority of the vault ATA)
+    #[account(
+        seeds = [
+            b"vault",
+            owner.key().as_ref(),
+            &bot_id_hash,
+        ],
+        bump
+    )]
+    pub vault: AccountInfo<'info>,
+
+    /// CHECK: Owner or pre-registered recovery address (authority of destination_ata)
+    #[account(
+        constraint = bot_meta.is_recovery_destination(destination.key)
+            @ SolscopeError::InvalidRecoveryDestination
+    )]
+    pub destination: AccountInfo<'info>,
+
+    pub mint: Account<'info, Mint>,
+
+    /// Vault ATA for this mint (source)
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = vault
+    )]
+    pub vault_ata: Account<'info, TokenAccount>,
+
+    /// Destination ATA for this mint
+    #[account(
+        init_if_needed,
+        payer = owner,
+        associated_token::mint = mint,
+        associated_token::authority = destination
+    )]
+    pub destination_ata: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(
+    ctx: Context<EmergencyWithdrawToken>,
+    bot_id_hash: [u8; 32],
+    amount: u64,
+) -> Result<()> {
+    require!(amount > 0, SolscopeError::InvalidAmount);
+
+    // hard gates (inverse of withdraw_token: only while Paused)
+    require!(
+        ctx.accounts.bot_meta.bot_id_hash == bot_id_hash,
+        SolscopeError::BotIdMismatch
+    );
+    ctx.accounts.bot_meta.require_allows(BotAction::EmergencyWithdraw)?;
+
+    require!(
+        ctx.accounts.vault_ata.amount >= amount,
+        SolscopeError::InsufficientVaultFunds
+    );
+
+    let seeds: &[&[u8]] = &[
+        b"vault",
+        ctx.accounts.owner.key.as_ref(),
+        &bot_id_hash,
+        &[ctx.bumps.vault],
+    ];
+    let signer_seeds = &[seeds];
+
+    let cpi_ctx = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        TransferChecked {
+            from: ctx.accounts.vault_ata.to_account_info(),
+            mint: ctx.accounts.mint.to_account_info(),
+            to: ctx.accounts.destination_ata.to_account_info(),
+            authority: ctx.accounts.vault.to_account_info(),
+        },
+        signer_seeds,
+    );
+
+    token::transfer_checked(cpi_ctx, amount, ctx.accounts.mint.decimals)?;
+
+    emit!(EmergencyTokenWithdrawal {
+        bot_meta: ctx.accounts.bot_meta.key(),
+        owner: ctx.accounts.owner.key(),
+        destination: ctx.accounts.destination.key(),
+        mint: ctx.accounts.mint.key(),
+        amount,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
@@ -16,6 +16,12 @@ pub enum SolscopeError {
     #[msg("Invalid amount")]
     InvalidAmount,
 
+    #[msg("Withdrawal would leave vault below rent-exempt minimum")]
+    VaultBelowRentExempt,
+
+    #[msg("Vault token account is not empty")]
+    VaultTokenAccountNotEmpty,
+
     /* ======================================================
      * Bot Identity & State Errors
      * ====================================================== */
@@ -25,15 +31,171 @@ pub enum SolscopeError {
     #[msg("Bot is paused")]
     BotPaused,
 
+    #[msg("Funding is paused")]
+    FundingPaused,
+
+    #[msg("Withdrawals are paused")]
+    WithdrawalsPaused,
+
+    #[msg("New positions are paused (sells only)")]
+    NewPositionsPaused,
+
+    #[msg("Unknown pause flag bits")]
+    InvalidPauseFlags,
+
+    #[msg("Bot must be paused")]
+    BotNotPaused,
+
+    #[msg("Bot is liquidating (sells and withdrawals only)")]
+    BotLiquidating,
+
+    #[msg("Bot is closing (withdrawals and close only)")]
+    BotClosing,
+
+    #[msg("Bot is closed")]
+    BotClosed,
+
+    #[msg("Invalid bot status transition")]
+    InvalidStatusTransition,
+
     #[msg("Invalid BotMeta bump")]
     InvalidBotMetaBump,
 
+    #[msg("Account is not a BotMeta of this owner and bot ID")]
+    InvalidBotMeta,
+
+    /* ======================================================
+     * Session Key Errors
+     * ====================================================== */
+    #[msg("Session key has expired")]
+    SessionKeyExpired,
+
+    #[msg("Trade exceeds session key per-trade limit")]
+    SessionTradeLimitExceeded,
+
+    #[msg("Session key budget exhausted")]
+    SessionBudgetExhausted,
+
+    #[msg("Every session key must be revoked or passed to close_bot")]
+    SessionKeysOpen,
+
+    /* ======================================================
+     * Risk Limit Errors
+     * ====================================================== */
+    #[msg("RiskConfig account required for this bot")]
+    RiskConfigMissing,
+
+    #[msg("BUY exceeds per-trade lamport limit")]
+    RiskBuyLimitExceeded,
+
+    #[msg("SELL exceeds per-trade token limit")]
+    RiskSellLimitExceeded,
+
+    #[msg("Daily notional limit exceeded")]
+    RiskDailyNotionalExceeded,
+
+    #[msg("Daily trade count limit exceeded")]
+    RiskDailyTradesExceeded,
+
+    #[msg("Daily notional limit set but the swap has no SOL leg to count")]
+    RiskNotionalUnpriced,
+
+    /* ======================================================
+     * Mint Policy Errors
+     * ====================================================== */
+    #[msg("MintPolicy account required for this bot")]
+    MintPolicyMissing,
+
+    #[msg("Mint is not allowed by the bot's mint policy")]
+    MintNotAllowed,
+
+    #[msg("Mint policy is full")]
+    MintPolicyFull,
+
+    /* ======================================================
+     * Pool Validation Errors
+     * ====================================================== */
+    #[msg("PoolAllowlist account required for this bot")]
+    PoolAllowlistMissing,
+
+    #[msg("Pool is not on the bot's allowlist")]
+    PoolNotAllowed,
+
+    #[msg("Pool allowlist is full")]
+    PoolAllowlistFull,
+
+    #[msg("Pool account does not match the AMM state")]
+    InvalidPoolAccount,
+
+    #[msg("Pool mints do not match the traded mint")]
+    PoolMintMismatch,
+
+    #[msg("Pool is not open for swaps")]
+    PoolNotTradable,
+
+    /* ======================================================
+     * DEX Adapter Errors
+     * ====================================================== */
+    #[msg("Unknown DEX id")]
+    UnknownDex,
+
+    #[msg("Unexpected accounts after the venue accounts")]
+    UnexpectedVenueAccounts,
+
+    /* ======================================================
+     * Route Errors
+     * ====================================================== */
+    #[msg("Route must have 1-3 hops with matching path and pool accounts (CLMM / DLMM last)")]
+    InvalidRoute,
+
+    #[msg("Route touches a vault account outside the checked source/destination")]
+    UnexpectedVaultAccount,
+
+    /* ======================================================
+     * OpenBook Errors
+     * ====================================================== */
+    #[msg("No open-orders account recorded for this market")]
+    OpenOrdersNotFound,
+
+    #[msg("An open-orders account is already recorded for this market")]
+    OpenOrdersExists,
+
+    #[msg("Open-orders record is full")]
+    OpenOrdersRecordFull,
+
+    #[msg("OpenOrdersRecord required for this bot")]
+    OpenOrdersRecordMissing,
+
+    #[msg("Every open-orders account must be closed first")]
+    OpenOrdersRecordNotEmpty,
+
+    /* ======================================================
+     * Oracle Errors
+     * ====================================================== */
+    #[msg("OracleConfig and oracle accounts required for this bot")]
+    OracleConfigMissing,
+
+    #[msg("Oracle account is not the configured Pyth price account for this mint")]
+    InvalidOracleAccount,
+
+    #[msg("Oracle price is stale, not trading or unusable")]
+    OraclePriceUnavailable,
+
+    #[msg("Fill price deviates from the oracle beyond the configured band")]
+    OracleDeviationExceeded,
+
+    #[msg("This trade path can't be checked against the bot's oracle")]
+    OracleUncheckedPath,
+
     /* ======================================================
      * Generic / Safety
      * ====================================================== */
     #[msg("Unauthorized operation")]
     Unauthorized,
 
+    #[msg("Destination is not the owner or recovery address")]
+    InvalidRecoveryDestination,
+
     #[msg("Slippage exceeded minimum output")]
     SlippageExceeded,
 }
@@ -0,0 +1,25 @@
+// programs/solscope/src/events.rs
+
+use anchor_lang::prelude::*;
+
+/* ======================================================
+ * Emergency Events (for off-chain monitoring)
+ * ====================================================== */
+#[event]
+pub struct EmergencyWithdrawal {
+    pub bot_meta: Pubkey,
+    pub owner: Pubkey,
+    pub destination: Pubkey,
+    pub amount: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct EmergencyTokenWithdrawal {
+    pub bot_meta: Pubkey,
+    pub owner: Pubkey,
+    pub destination: Pubkey,
+    pub mint: Pubkey,
+    pub amount: u64,
+    pub timestamp: i64,
+}
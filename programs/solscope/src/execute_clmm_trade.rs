@@ -0,0 +1,34 @@
+// programs/solscope/src/execute_clmm_trade.rs
+
+use anchor_lang::prelude::*;
+
+use crate::{
+    dex::SwapMode,
+    execute_trade::{self, ExecuteTrade},
+    raydium_clmm::RaydiumClmmVenue,
+};
+
+/// SOL<->token trade against a Raydium CLMM pool. Uses the ExecuteTrade accounts;
+/// remaining_accounts = RaydiumClmmAccounts, then the tick arrays the swap crosses.
+/// `sqrt_price_limit_x64` (Q64.64, 0 = none) stops the swap at that price.
+pub fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, ExecuteTrade<'info>>,
+    bot_id_hash: [u8; 32],
+    side: u8,
+    amount_in: u64,
+    min_out: u64,
+    sqrt_price_limit_x64: u128,
+) -> Result<()> {
+    let mut venue_accounts: &'info [AccountInfo<'info>] = ctx.remaining_accounts;
+    let venue = RaydiumClmmVenue::load_with_price_limit(&mut venue_accounts, sqrt_price_limit_x64)?;
+
+    execute_trade::execute(
+        ctx,
+        bot_id_hash,
+        Box::new(venue),
+        side,
+        SwapMode::ExactIn,
+        amount_in,
+        min_out,
+    )
+}
@@ -0,0 +1,528 @@
+// programs/solscope/src/execute_jupiter_route.rs
+
+use std::str::FromStr;
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::{AccountMeta, Instruction};
+use anchor_lang::solana_program::program::invoke_signed;
+
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::{self, Mint, Token, TokenAccount},
+};
+use spl_token::native_mint;
+
+use crate::{
+    dex::token_2022_program_id,
+    errors::SolscopeError,
+    execute_trade,
+    guards,
+    state::{BotAction, BotMeta, BotStatus, MintPolicy, OracleConfig, RiskConfig, SessionKey},
+};
+
+#[cfg(not(any(test, feature = "jupiter-stand-in")))]
+pub fn jupiter_program_id() -> Pubkey {
+    Pubkey::from_str("JUP6LkbZbjS1jKKwapdHNy74zcZ3tLUZoi5QNyVTaV4").unwrap()
+}
+
+/// Local stand-in for the aggregator (unit tests / `jupiter-stand-in` test builds)
+#[cfg(any(test, feature = "jupiter-stand-in"))]
+pub fn jupiter_program_id() -> Pubkey {
+    Pubkey::from_str("JupStandin111111111111111111111111111111111").unwrap()
+}
+
+/// Base SPL token account length (Token-2022 accounts start with the same layout)
+const TOKEN_ACCOUNT_LEN: usize = 165;
+/// Multisig length; Token-2022 never sizes an extended account to it
+const TOKEN_MULTISIG_LEN: usize = 355;
+/// Token-2022 AccountType byte right after the base layout (2 = Account)
+const TOKEN_2022_ACCOUNT_TYPE_ACCOUNT: u8 = 2;
+
+/// True if `info` is an SPL Token or Token-2022 token account the vault owns,
+/// is delegate of, or can close
+fn is_vault_token_account(info: &AccountInfo, vault: &Pubkey) -> Result<bool> {
+    let token_2022 = info.owner == &token_2022_program_id();
+    if info.owner != &token::ID && !token_2022 {
+        return Ok(false);
+    }
+    let data = info.try_borrow_data()?;
+    let is_account = data.len() == TOKEN_ACCOUNT_LEN
+        || (token_2022
+            && data.len() > TOKEN_ACCOUNT_LEN
+            && data.len() != TOKEN_MULTISIG_LEN
+            && data[TOKEN_ACCOUNT_LEN] == TOKEN_2022_ACCOUNT_TYPE_ACCOUNT);
+    if !is_account {
+        return Ok(false);
+    }
+
+    // owner, then COption<Pubkey> delegate / close_authority (u32 tag + key)
+    let is_set_to = |tag: usize| {
+        data[tag..tag + 4] == 1u32.to_le_bytes() && data[tag + 4..tag + 36] == vault.as_ref()[..]
+    };
+    Ok(data[32..64] == vault.as_ref()[..] || is_set_to(72) || is_set_to(129))
+}
+
+/// The vault's signature covers every vault token account: the route may
+/// only touch the two ATAs this instruction checks
+pub fn check_route_accounts(
+    accounts: &[AccountInfo],
+    vault: &Pubkey,
+    source: &Pubkey,
+    dest: &Pubkey,
+) -> Result<()> {
+    for info in accounts {
+        if info.key == source || info.key == dest {
+            continue;
+        }
+        require!(
+            !is_vault_token_account(info, vault)?,
+            SolscopeError::UnexpectedVaultAccount
+        );
+    }
+    Ok(())
+}
+
+/// Vault balances the route is checked against
+#[derive(Clone, Copy)]
+pub struct VaultBalances {
+    pub lamports: u64,
+    pub source: u64,
+    pub dest: u64,
+}
+
+impl VaultBalances {
+    pub fn read(vault: &AccountInfo, source: &AccountInfo, dest: &AccountInfo) -> Result<Self> {
+        Ok(Self {
+            lamports: vault.lamports(),
+            source: token::accessor::amount(source)?,
+            dest: token::accessor::amount(dest)?,
+        })
+    }
+}
+
+/// (spent, received) of a finished route; same slippage checks as execute_trade,
+/// and the vault signature must not have paid for anything in SOL
+pub fn check_route_deltas(
+    before: VaultBalances,
+    after: VaultBalances,
+    amount_in: u64,
+    min_out: u64,
+) -> Result<(u64, u64)> {
+    let deltas = execute_trade::swap_deltas(
+        (before.source, after.source),
+        (before.dest, after.dest),
+        amount_in,
+        min_out,
+    )?;
+    require!(
+        after.lamports >= before.lamports,
+        SolscopeError::UnexpectedVaultAccount
+    );
+    Ok(deltas)
+}
+
+/// Route instruction for `program`; only the vault signs, caller signatures are not forwarded
+pub fn route_ix(
+    program: &Pubkey,
+    accounts: &[AccountInfo],
+    vault: &Pubkey,
+    route_data: Vec<u8>,
+) -> Instruction {
+    let metas = accounts
+        .iter()
+        .map(|info| AccountMeta {
+            pubkey: *info.key,
+            is_signer: info.key == vault,
+            is_writable: info.is_writable,
+        })
+        .collect();
+
+    Instruction {
+        program_id: *program,
+        accounts: metas,
+        data: route_data,
+    }
+}
+
+/// Opaque Jupiter route: `route_data` + remaining_accounts are forwarded as-is,
+/// with the vault PDA as the only signer. Solscope enforces its own guards around it.
+#[derive(Accounts)]
+#[instruction(bot_id_hash: [u8; 32])]
+pub struct ExecuteJupiterRoute<'info> {
+    /// Owner, delegated operator or live session key (pays for vault ATA creation)
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// BotMeta PDA (derived from the stored owner, not the signer)
+    #[account(
+        seeds = [b"bot", bot_meta.owner.as_ref(), &bot_id_hash],
+        bump,
+        has_one = vault
+    )]
+    pub bot_meta: Account<'info, BotMeta>,
+
+    /// CHECK: Vault PDA (route's user transfer authority)
+    #[account(
+        mut,
+        seeds = [b"vault", bot_meta.owner.as_ref(), &bot_id_hash],
+        bump
+    )]
+    pub vault: AccountInfo<'info>,
+
+    /// SessionKey PDA (required only when authority is a session key)
+    #[account(
+        mut,
+        seeds = [b"session", bot_meta.key().as_ref(), authority.key().as_ref()],
+        bump = session_key.bump,
+        has_one = bot_meta,
+    )]
+    pub session_key: Option<Account<'info, SessionKey>>,
+
+    /// RiskConfig PDA (required once the owner has configured limits)
+    #[account(
+        mut,
+        seeds = [b"risk", bot_meta.key().as_ref()],
+        bump = risk_config.bump,
+        has_one = bot_meta,
+    )]
+    pub risk_config: Option<Account<'info, RiskConfig>>,
+
+    /// MintPolicy PDA (required once the owner has created one)
+    #[account(
+        seeds = [b"mint_policy", bot_meta.key().as_ref()],
+        bump = mint_policy.bump,
+        has_one = bot_meta,
+    )]
+    pub mint_policy: Option<Account<'info, MintPolicy>>,
+
+    /// OracleConfig PDA (required once the owner has configured a price band)
+    #[account(
+        seeds = [b"oracle", bot_meta.key().as_ref()],
+        bump = oracle_config.bump,
+        has_one = bot_meta,
+    )]
+    pub oracle_config: Option<Account<'info, OracleConfig>>,
+
+    /// CHECK: Pyth-layout price account named by oracle_config
+    pub oracle: Option<UncheckedAccount<'info>>,
+
+    /// Input token mint (wSOL for SOL -> token)
+    pub source_mint: Account<'info, Mint>,
+
+    /// Output token mint (wSOL for token -> SOL)
+    #[account(constraint = dest_mint.key() != source_mint.key() @ SolscopeError::InvalidAmount)]
+    pub dest_mint: Account<'info, Mint>,
+
+    /// Vault ATA spending `source_mint`
+    #[account(
+        mut,
+        associated_token::mint = source_mint,
+        associated_token::authority = vault
+    )]
+    pub vault_source_ata: Account<'info, TokenAccount>,
+
+    /// Vault ATA receiving `dest_mint`
+    #[account(
+        init_if_needed,
+        payer = authority,
+        associated_token::mint = dest_mint,
+        associated_token::authority = vault
+    )]
+    pub vault_dest_ata: Account<'info, TokenAccount>,
+
+    /// CHECK: Jupiter aggregator program (the only program routes may target)
+    #[account(address = jupiter_program_id())]
+    pub jupiter_program: AccountInfo<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, ExecuteJupiterRoute<'info>>,
+    bot_id_hash: [u8; 32],
+    route_data: Vec<u8>,
+    amount_in: u64,
+    min_out: u64,
+) -> Result<()> {
+    require!(amount_in > 0, SolscopeError::InvalidAmount);
+    require!(min_out > 0, SolscopeError::InvalidAmount);
+
+    require!(
+        ctx.accounts.bot_meta.bot_id_hash == bot_id_hash,
+        SolscopeError::BotIdMismatch
+    );
+
+    // wSOL at either end makes the route a BUY (SOL in) or SELL (SOL out)
+    let source_mint = ctx.accounts.source_mint.key();
+    let dest_mint = ctx.accounts.dest_mint.key();
+    let sol_in = source_mint == native_mint::id();
+    let sol_out = dest_mint == native_mint::id();
+
+    // exiting to SOL, or any route while liquidating, unwinds a position; the rest open one
+    let action = if sol_out || ctx.accounts.bot_meta.status == BotStatus::Liquidating {
+        BotAction::Sell
+    } else {
+        BotAction::Buy
+    };
+    ctx.accounts.bot_meta.require_allows(action)?;
+
+    // the oracle prices a token in SOL; a token -> token fill has nothing to check against
+    if !sol_in && !sol_out {
+        guards::require_no_oracle(&ctx.accounts.bot_meta)?;
+    }
+
+    /* ================= Signer authorization ================= */
+    guards::authorize_trader(
+        &ctx.accounts.bot_meta,
+        ctx.accounts.authority.key,
+        ctx.accounts.session_key.as_mut(),
+        sol_in.then_some(amount_in),
+    )?;
+
+    /* ================= Risk limits (pre-trade) ================= */
+    if let Some(risk_config) =
+        guards::load_risk_config(&ctx.accounts.bot_meta, ctx.accounts.risk_config.as_mut())?
+    {
+        risk_config.check_trade(sol_in, &source_mint, amount_in)?;
+        if !sol_in && !sol_out {
+            risk_config.check_unpriced_trade()?;
+        }
+    }
+
+    /* ================= Mint policy + venue restrictions ================= */
+    guards::check_mint_policy(
+        &ctx.accounts.bot_meta,
+        ctx.accounts.mint_policy.as_ref(),
+        &[source_mint, dest_mint],
+    )?;
+
+    // the pools inside an opaque route can't be checked against an allowlist
+    require!(
+        ctx.accounts.bot_meta.pool_allowlist.is_none(),
+        SolscopeError::PoolNotAllowed
+    );
+
+    require!(
+        ctx.accounts.vault_source_ata.amount >= amount_in,
+        SolscopeError::InsufficientVaultFunds
+    );
+
+    /* ================= Route accounts ================= */
+    let vault_key = ctx.accounts.vault.key();
+    let source_key = ctx.accounts.vault_source_ata.key();
+    let dest_key = ctx.accounts.vault_dest_ata.key();
+
+    check_route_accounts(ctx.remaining_accounts, &vault_key, &source_key, &dest_key)?;
+
+    let ix = route_ix(
+        ctx.accounts.jupiter_program.key,
+        ctx.remaining_accounts,
+        &vault_key,
+        route_data,
+    );
+
+    /* ================= PDA signer ================= */
+    let owner = ctx.accounts.bot_meta.owner;
+    let vault_seeds: &[&[u8]] = &[
+        b"vault",
+        owner.as_ref(),
+        &bot_id_hash,
+        &[ctx.bumps.vault],
+    ];
+    let signer_seeds = &[vault_seeds];
+
+    /* ================= Pre-route balance snapshots ================= */
+    let vault_info = ctx.accounts.vault.to_account_info();
+    let source_info = ctx.accounts.vault_source_ata.to_account_info();
+    let dest_info = ctx.accounts.vault_dest_ata.to_account_info();
+    let before = VaultBalances::read(&vault_info, &source_info, &dest_info)?;
+
+    /* ================= Jupiter CPI ================= */
+    let mut infos = ctx.remaining_accounts.to_vec();
+    infos.push(vault_info.clone());
+    infos.push(ctx.accounts.jupiter_program.to_account_info());
+
+    invoke_signed(&ix, &infos, signer_seeds)?;
+
+    /* ================= Post-route delta checks ================= */
+    let after = VaultBalances::read(&vault_info, &source_info, &dest_info)?;
+    let (spent, received) = check_route_deltas(before, after, amount_in, min_out)?;
+
+    /* ================= Oracle price band (token -> token was refused above) ================= */
+    if sol_in || sol_out {
+        guards::check_swap_oracle_price(
+            &ctx.accounts.bot_meta,
+            ctx.accounts.oracle_config.as_ref(),
+            ctx.accounts.oracle.as_deref(),
+            &ctx.accounts.source_mint,
+            &ctx.accounts.dest_mint,
+            spent,
+            received,
+        )?;
+    }
+
+    /* ================= Risk limits (post-trade accounting) ================= */
+    // lamport notional: SOL spent on the way in, or SOL received on the way out
+    let notional = if sol_in {
+        spent
+    } else if sol_out {
+        received
+    } else {
+        0
+    };
+
+    if let Some(risk_config) = ctx.accounts.risk_config.as_mut() {
+        risk_config.record_trade(notional)?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{put, token_account_data, TestAccount};
+
+    fn balances(lamports: u64, source: u64, dest: u64) -> VaultBalances {
+        VaultBalances {
+            lamports,
+            source,
+            dest,
+        }
+    }
+
+    #[test]
+    fn route_deltas_reject_drained_source_and_short_output() {
+        let before = balances(10_000, 5_000, 0);
+
+        let deltas = check_route_deltas(before, balances(10_000, 4_000, 300), 1_000, 250).unwrap();
+        assert_eq!(deltas, (1_000, 300));
+
+        // route pulled more than amount_in out of the source ATA
+        let err = check_route_deltas(before, balances(10_000, 0, 300), 1_000, 250).unwrap_err();
+        assert_eq!(err, SolscopeError::SlippageExceeded.into());
+
+        // destination received less than min_out
+        let err = check_route_deltas(before, balances(10_000, 4_000, 249), 1_000, 250).unwrap_err();
+        assert_eq!(err, SolscopeError::SlippageExceeded.into());
+
+        // vault lamports paid for something inside the route
+        let err = check_route_deltas(before, balances(9_999, 4_000, 300), 1_000, 250).unwrap_err();
+        assert_eq!(err, SolscopeError::UnexpectedVaultAccount.into());
+    }
+
+    struct Route {
+        vault: Pubkey,
+        source: TestAccount,
+        dest: TestAccount,
+    }
+
+    impl Route {
+        fn new() -> Self {
+            let vault = Pubkey::new_unique();
+            Self {
+                vault,
+                source: TestAccount::new(
+                    token::ID,
+                    token_account_data(&Pubkey::new_unique(), &vault, 5),
+                ),
+                dest: TestAccount::new(
+                    token::ID,
+                    token_account_data(&Pubkey::new_unique(), &vault, 0),
+                ),
+            }
+        }
+
+        fn check(&mut self, extra: &mut TestAccount) -> Result<()> {
+            let (source, dest) = (self.source.key, self.dest.key);
+            let pool = &mut TestAccount::new(
+                token::ID,
+                token_account_data(&source, &Pubkey::new_unique(), 9),
+            );
+            let infos = vec![
+                self.source.info(),
+                pool.info(),
+                extra.info(),
+                self.dest.info(),
+            ];
+            check_route_accounts(&infos, &self.vault, &source, &dest)
+        }
+    }
+
+    #[test]
+    fn route_may_only_touch_the_checked_vault_atas() {
+        let mut route = Route::new();
+        let vault = route.vault;
+        let mint = Pubkey::new_unique();
+
+        // someone else's token account and a non-token account pass
+        let mut other = TestAccount::new(
+            token::ID,
+            token_account_data(&mint, &Pubkey::new_unique(), 1),
+        );
+        route.check(&mut other).unwrap();
+        let mut program_state = TestAccount::new(Pubkey::new_unique(), vec![0; 165]);
+        route.check(&mut program_state).unwrap();
+
+        // stray vault-owned accounts under either token program
+        let mut stray = TestAccount::new(token::ID, token_account_data(&mint, &vault, 1));
+        let err = route.check(&mut stray).unwrap_err();
+        assert_eq!(err, SolscopeError::UnexpectedVaultAccount.into());
+
+        let mut stray_2022 = TestAccount::new(
+            token_2022_program_id(),
+            token_account_data(&mint, &vault, 1),
+        );
+        let err = route.check(&mut stray_2022).unwrap_err();
+        assert_eq!(err, SolscopeError::UnexpectedVaultAccount.into());
+    }
+
+    #[test]
+    fn extended_token_2022_accounts_and_vault_authorities_are_caught() {
+        let mut route = Route::new();
+        let vault = route.vault;
+        let mint = Pubkey::new_unique();
+        let stranger = Pubkey::new_unique();
+
+        // Token-2022 account with extensions: AccountType::Account after the base layout
+        let mut data = token_account_data(&mint, &vault, 1);
+        data.extend_from_slice(&[TOKEN_2022_ACCOUNT_TYPE_ACCOUNT, 0, 0, 0]);
+        let mut extended = TestAccount::new(token_2022_program_id(), data);
+        let err = route.check(&mut extended).unwrap_err();
+        assert_eq!(err, SolscopeError::UnexpectedVaultAccount.into());
+
+        // vault set as delegate, then as close authority, of someone else's account
+        for tag in [72, 129] {
+            let mut data = token_account_data(&mint, &stranger, 1);
+            put(&mut data, tag, &1u32.to_le_bytes());
+            put(&mut data, tag + 4, vault.as_ref());
+            let mut delegated = TestAccount::new(token_2022_program_id(), data);
+            let err = route.check(&mut delegated).unwrap_err();
+            assert_eq!(err, SolscopeError::UnexpectedVaultAccount.into());
+        }
+    }
+
+    #[test]
+    fn route_ix_targets_the_allowed_program_with_only_the_vault_signing() {
+        let mut route = Route::new();
+        let vault = route.vault;
+        let mut vault_account = TestAccount::with_key(vault, System::id(), vec![]);
+        let infos = vec![route.source.info(), vault_account.info(), route.dest.info()];
+
+        let ix = route_ix(&jupiter_program_id(), &infos, &vault, vec![1, 2, 3]);
+        assert_eq!(
+            ix.program_id,
+            Pubkey::from_str("JupStandin111111111111111111111111111111111").unwrap()
+        );
+        assert_eq!(ix.data, vec![1, 2, 3]);
+        let signers: Vec<Pubkey> = ix
+            .accounts
+            .iter()
+            .filter(|m| m.is_signer)
+            .map(|m| m.pubkey)
+            .collect();
+        assert_eq!(signers, vec![vault]);
+    }
+}
@@ -0,0 +1,252 @@
+// programs/solscope/src/execute_pump_trade.rs
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program::invoke_signed;
+
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::{self, Mint, Token, TokenAccount},
+};
+
+// glob: Anchor's composite-accounts codegen needs the generated client modules
+use crate::pump_fun::*;
+use crate::{
+    errors::SolscopeError,
+    execute_trade::{SIDE_BUY, SIDE_SELL},
+    guards,
+    state::{BotAction, BotMeta, MintPolicy, OracleConfig, PoolAllowlist, RiskConfig, SessionKey},
+};
+
+#[derive(Accounts)]
+#[instruction(bot_id_hash: [u8; 32])]
+pub struct ExecutePumpTrade<'info> {
+    /// Owner, delegated operator or live session key (pays for vault ATA creation)
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// BotMeta PDA (derived from the stored owner, not the signer)
+    #[account(
+        seeds = [b"bot", bot_meta.owner.as_ref(), &bot_id_hash],
+        bump,
+        has_one = vault
+    )]
+    pub bot_meta: Account<'info, BotMeta>,
+
+    /// CHECK: Vault PDA (pays SOL on BUY, receives SOL on SELL; no wSOL leg)
+    #[account(
+        mut,
+        seeds = [b"vault", bot_meta.owner.as_ref(), &bot_id_hash],
+        bump
+    )]
+    pub vault: AccountInfo<'info>,
+
+    /// SessionKey PDA (required only when authority is a session key)
+    #[account(
+        mut,
+        seeds = [b"session", bot_meta.key().as_ref(), authority.key().as_ref()],
+        bump = session_key.bump,
+        has_one = bot_meta,
+    )]
+    pub session_key: Option<Account<'info, SessionKey>>,
+
+    /// RiskConfig PDA (required once the owner has configured limits)
+    #[account(
+        mut,
+        seeds = [b"risk", bot_meta.key().as_ref()],
+        bump = risk_config.bump,
+        has_one = bot_meta,
+    )]
+    pub risk_config: Option<Account<'info, RiskConfig>>,
+
+    /// MintPolicy PDA (required once the owner has created one)
+    #[account(
+        seeds = [b"mint_policy", bot_meta.key().as_ref()],
+        bump = mint_policy.bump,
+        has_one = bot_meta,
+    )]
+    pub mint_policy: Option<Account<'info, MintPolicy>>,
+
+    /// PoolAllowlist PDA (required once the owner has created one; lists bonding curves)
+    #[account(
+        seeds = [b"pools", bot_meta.key().as_ref()],
+        bump = pool_allowlist.bump,
+        has_one = bot_meta,
+    )]
+    pub pool_allowlist: Option<Account<'info, PoolAllowlist>>,
+
+    /// OracleConfig PDA (required once the owner has configured a price band)
+    #[account(
+        seeds = [b"oracle", bot_meta.key().as_ref()],
+        bump = oracle_config.bump,
+        has_one = bot_meta,
+    )]
+    pub oracle_config: Option<Account<'info, OracleConfig>>,
+
+    /// CHECK: Pyth-layout price account named by oracle_config
+    pub oracle: Option<UncheckedAccount<'info>>,
+
+    /// Bonding-curve token mint
+    pub mint: Account<'info, Mint>,
+
+    /// Vault ATA for this mint.
+    /// BUY: destination (receives tokens)
+    /// SELL: source (spends tokens)
+    #[account(
+        init_if_needed,
+        payer = authority,
+        associated_token::mint = mint,
+        associated_token::authority = vault
+    )]
+    pub vault_ata: Account<'info, TokenAccount>,
+
+    /* ========== pump.fun Accounts ========== */
+    pub pump: PumpFunAccounts<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+/// BUY: receive `token_amount` tokens for at most `sol_amount` lamports.
+/// SELL: sell `token_amount` tokens for at least `sol_amount` lamports.
+pub fn handler(
+    ctx: Context<ExecutePumpTrade>,
+    bot_id_hash: [u8; 32],
+    side: u8,
+    token_amount: u64,
+    sol_amount: u64,
+) -> Result<()> {
+    require!(token_amount > 0, SolscopeError::InvalidAmount);
+    require!(sol_amount > 0, SolscopeError::InvalidAmount);
+    require!(
+        side == SIDE_BUY || side == SIDE_SELL,
+        SolscopeError::Unauthorized
+    );
+    let is_buy = side == SIDE_BUY;
+
+    require!(
+        ctx.accounts.bot_meta.bot_id_hash == bot_id_hash,
+        SolscopeError::BotIdMismatch
+    );
+    // SELL stays open while liquidating / new positions are paused
+    let action = if is_buy { BotAction::Buy } else { BotAction::Sell };
+    ctx.accounts.bot_meta.require_allows(action)?;
+
+    // what the vault may spend: lamports on BUY, tokens on SELL
+    let amount_in = if is_buy { sol_amount } else { token_amount };
+
+    /* ================= Signer authorization ================= */
+    guards::authorize_trader(
+        &ctx.accounts.bot_meta,
+        ctx.accounts.authority.key,
+        ctx.accounts.session_key.as_mut(),
+        is_buy.then_some(sol_amount),
+    )?;
+
+    /* ================= Risk limits (pre-trade) ================= */
+    if let Some(risk_config) =
+        guards::load_risk_config(&ctx.accounts.bot_meta, ctx.accounts.risk_config.as_mut())?
+    {
+        risk_config.check_trade(is_buy, &ctx.accounts.mint.key(), amount_in)?;
+    }
+
+    /* ================= Mint policy + curve validation ================= */
+    let mint = ctx.accounts.mint.key();
+    guards::check_mint_policy(
+        &ctx.accounts.bot_meta,
+        ctx.accounts.mint_policy.as_ref(),
+        &[mint],
+    )?;
+    guards::check_pool_allowlist(
+        &ctx.accounts.bot_meta,
+        ctx.accounts.pool_allowlist.as_ref(),
+        ctx.accounts.pump.bonding_curve.key,
+    )?;
+    ctx.accounts.pump.validate(&mint)?;
+
+    let vault_info = ctx.accounts.vault.to_account_info();
+    let token_info = ctx.accounts.vault_ata.to_account_info();
+    if is_buy {
+        require!(
+            vault_info.lamports() >= sol_amount,
+            SolscopeError::InsufficientVaultFunds
+        );
+    } else {
+        require!(
+            ctx.accounts.vault_ata.amount >= token_amount,
+            SolscopeError::InsufficientVaultFunds
+        );
+    }
+
+    /* ================= PDA signer ================= */
+    let owner = ctx.accounts.bot_meta.owner;
+    let vault_seeds: &[&[u8]] = &[
+        b"vault",
+        owner.as_ref(),
+        &bot_id_hash,
+        &[ctx.bumps.vault],
+    ];
+    let signer_seeds = &[vault_seeds];
+
+    /* ================= Pre-trade balance snapshots ================= */
+    let before_lamports = vault_info.lamports();
+    let before_tokens = token::accessor::amount(&token_info)?;
+
+    /* ================= pump.fun buy / sell ================= */
+    let ix = ctx.accounts.pump.trade_ix(
+        is_buy,
+        &mint,
+        token_info.key,
+        vault_info.key,
+        token_amount,
+        sol_amount,
+    );
+
+    invoke_signed(&ix, &ctx.accounts.to_account_infos(), signer_seeds)?;
+
+    /* ================= Post-trade delta checks ================= */
+    let after_lamports = vault_info.lamports();
+    let after_tokens = token::accessor::amount(&token_info)?;
+
+    // lamport notional of this trade (BUY: SOL spent, SELL: SOL received) and the tokens moved
+    let (notional, tokens) = if is_buy {
+        let spent = before_lamports.saturating_sub(after_lamports);
+        require!(spent <= sol_amount, SolscopeError::SlippageExceeded);
+
+        let received = after_tokens.saturating_sub(before_tokens);
+        require!(received >= token_amount, SolscopeError::SlippageExceeded);
+
+        // SOL leaves the vault directly, so it must stay rent-exempt
+        require!(
+            after_lamports >= Rent::get()?.minimum_balance(0),
+            SolscopeError::VaultBelowRentExempt
+        );
+        (spent, received)
+    } else {
+        let spent = before_tokens.saturating_sub(after_tokens);
+        require!(spent <= token_amount, SolscopeError::SlippageExceeded);
+
+        let received = after_lamports.saturating_sub(before_lamports);
+        require!(received >= sol_amount, SolscopeError::SlippageExceeded);
+        (received, spent)
+    };
+
+    /* ================= Oracle price band ================= */
+    guards::check_oracle_price(
+        &ctx.accounts.bot_meta,
+        ctx.accounts.oracle_config.as_ref(),
+        ctx.accounts.oracle.as_deref(),
+        &mint,
+        is_buy,
+        notional,
+        tokens,
+        ctx.accounts.mint.decimals,
+    )?;
+
+    /* ================= Risk limits (post-trade accounting) ================= */
+    if let Some(risk_config) = ctx.accounts.risk_config.as_mut() {
+        risk_config.record_trade(notional)?;
+    }
+
+    Ok(())
+}
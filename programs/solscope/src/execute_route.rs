@@ -0,0 +1,298 @@
+// programs/solscope/src/execute_route.rs
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program::invoke_signed;
+
+use anchor_spl::token::{self, Token, TokenAccount};
+use spl_token::native_mint;
+
+use crate::{
+    dex::{self, SwapMode},
+    errors::SolscopeError,
+    guards,
+    state::{BotAction, BotMeta, BotStatus, MintPolicy, PoolAllowlist, RiskConfig, SessionKey},
+};
+
+pub const MAX_ROUTE_HOPS: usize = 3;
+
+/// `hops` lists the dex id of each hop. remaining_accounts layout for N hops:
+///   [0..=N]   vault token accounts along the path (source, intermediates..., destination)
+///   then each hop's venue accounts, back to back, in hop order
+/// CLMM / DLMM take every trailing account, so they may only be the last hop.
+#[derive(Accounts)]
+#[instruction(bot_id_hash: [u8; 32])]
+pub struct ExecuteRoute<'info> {
+    /// Owner, delegated operator or live session key
+    pub authority: Signer<'info>,
+
+    /// BotMeta PDA (derived from the stored owner, not the signer)
+    #[account(
+        seeds = [b"bot", bot_meta.owner.as_ref(), &bot_id_hash],
+        bump,
+        has_one = vault
+    )]
+    pub bot_meta: Account<'info, BotMeta>,
+
+    /// CHECK: Vault PDA (authority of every token account on the path)
+    #[account(
+        seeds = [b"vault", bot_meta.owner.as_ref(), &bot_id_hash],
+        bump
+    )]
+    pub vault: AccountInfo<'info>,
+
+    /// SessionKey PDA (required only when authority is a session key)
+    #[account(
+        mut,
+        seeds = [b"session", bot_meta.key().as_ref(), authority.key().as_ref()],
+        bump = session_key.bump,
+        has_one = bot_meta,
+    )]
+    pub session_key: Option<Account<'info, SessionKey>>,
+
+    /// RiskConfig PDA (required once the owner has configured limits)
+    #[account(
+        mut,
+        seeds = [b"risk", bot_meta.key().as_ref()],
+        bump = risk_config.bump,
+        has_one = bot_meta,
+    )]
+    pub risk_config: Option<Account<'info, RiskConfig>>,
+
+    /// MintPolicy PDA (required once the owner has created one)
+    #[account(
+        seeds = [b"mint_policy", bot_meta.key().as_ref()],
+        bump = mint_policy.bump,
+        has_one = bot_meta,
+    )]
+    pub mint_policy: Option<Account<'info, MintPolicy>>,
+
+    /// PoolAllowlist PDA (required once the owner has created one)
+    #[account(
+        seeds = [b"pools", bot_meta.key().as_ref()],
+        bump = pool_allowlist.bump,
+        has_one = bot_meta,
+    )]
+    pub pool_allowlist: Option<Account<'info, PoolAllowlist>>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+/// 1..=MAX_ROUTE_HOPS hops, with a trailing-account venue only in the last one
+pub fn check_hops(dexes: &[u8]) -> Result<()> {
+    require!(
+        (1..=MAX_ROUTE_HOPS).contains(&dexes.len()),
+        SolscopeError::InvalidRoute
+    );
+    require!(
+        !dexes[..dexes.len() - 1]
+            .iter()
+            .any(|dex| dex::takes_trailing_accounts(*dex)),
+        SolscopeError::InvalidRoute
+    );
+    Ok(())
+}
+
+pub fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, ExecuteRoute<'info>>,
+    bot_id_hash: [u8; 32],
+    hops: Vec<u8>,
+    amount_in: u64,
+    min_out: u64,
+) -> Result<()> {
+    require!(amount_in > 0, SolscopeError::InvalidAmount);
+    require!(min_out > 0, SolscopeError::InvalidAmount);
+
+    require!(
+        ctx.accounts.bot_meta.bot_id_hash == bot_id_hash,
+        SolscopeError::BotIdMismatch
+    );
+
+    /* ================= Route accounts ================= */
+    let dexes = hops;
+    check_hops(&dexes)?;
+    let hops = dexes.len();
+
+    let remaining: &'info [AccountInfo<'info>] = ctx.remaining_accounts;
+    require!(remaining.len() > hops, SolscopeError::InvalidRoute);
+    let (path_infos, mut venue_infos) = remaining.split_at(hops + 1);
+
+    let vault_key = ctx.accounts.vault.key();
+    let mut path_mints = Vec::with_capacity(hops + 1);
+    for info in path_infos {
+        let token_account = Account::<TokenAccount>::try_from(info)?;
+        require!(token_account.owner == vault_key, SolscopeError::InvalidVault);
+        path_mints.push(token_account.mint);
+    }
+    // a cycle (A -> B -> A) must land in a different account than it spends from
+    for (i, info) in path_infos.iter().enumerate() {
+        require!(
+            path_infos[i + 1..].iter().all(|other| other.key != info.key),
+            SolscopeError::InvalidRoute
+        );
+    }
+
+    let mut venues = Vec::with_capacity(hops);
+    for dex in dexes {
+        venues.push(dex::load_venue(dex, &mut venue_infos)?);
+    }
+    require!(venue_infos.is_empty(), SolscopeError::UnexpectedVenueAccounts);
+
+    // wSOL at either end makes the route a BUY (SOL in) or SELL (SOL out)
+    let source_mint = path_mints[0];
+    let dest_mint = path_mints[hops];
+    let sol_in = source_mint == native_mint::id();
+    let sol_out = dest_mint == native_mint::id();
+
+    // exiting to SOL, or any route while liquidating, unwinds a position; the rest open one
+    let action = if sol_out || ctx.accounts.bot_meta.status == BotStatus::Liquidating {
+        BotAction::Sell
+    } else {
+        BotAction::Buy
+    };
+    ctx.accounts.bot_meta.require_allows(action)?;
+
+    // path token accounts carry no decimals, so a route fill can't be priced in SOL
+    guards::require_no_oracle(&ctx.accounts.bot_meta)?;
+
+    /* ================= Signer authorization ================= */
+    guards::authorize_trader(
+        &ctx.accounts.bot_meta,
+        ctx.accounts.authority.key,
+        ctx.accounts.session_key.as_mut(),
+        sol_in.then_some(amount_in),
+    )?;
+
+    /* ================= Risk limits (pre-trade) ================= */
+    if let Some(risk_config) =
+        guards::load_risk_config(&ctx.accounts.bot_meta, ctx.accounts.risk_config.as_mut())?
+    {
+        risk_config.check_trade(sol_in, &source_mint, amount_in)?;
+        if !sol_in && !sol_out {
+            risk_config.check_unpriced_trade()?;
+        }
+    }
+
+    /* ================= Mint policy + venue validation ================= */
+    guards::check_mint_policy(
+        &ctx.accounts.bot_meta,
+        ctx.accounts.mint_policy.as_ref(),
+        &path_mints,
+    )?;
+
+    for (i, venue) in venues.iter_mut().enumerate() {
+        guards::check_pool_allowlist(
+            &ctx.accounts.bot_meta,
+            ctx.accounts.pool_allowlist.as_ref(),
+            &venue.pool_id(),
+        )?;
+
+        // each hop must swap its input mint into the next mint on the path
+        venue.validate(&path_mints[i], &path_mints[i + 1])?;
+    }
+
+    require!(
+        token::accessor::amount(&path_infos[0])? >= amount_in,
+        SolscopeError::InsufficientVaultFunds
+    );
+
+    /* ================= PDA signer ================= */
+    let owner = ctx.accounts.bot_meta.owner;
+    let vault_seeds: &[&[u8]] = &[
+        b"vault",
+        owner.as_ref(),
+        &bot_id_hash,
+        &[ctx.bumps.vault],
+    ];
+    let signer_seeds = &[vault_seeds];
+
+    /* ================= Hops (output of hop i feeds hop i + 1) ================= */
+    let before_source = token::accessor::amount(&path_infos[0])?;
+    let mut hop_amount = amount_in;
+
+    for (i, venue) in venues.iter().enumerate() {
+        let source_info = &path_infos[i];
+        let dest_info = &path_infos[i + 1];
+        let output = venue.output_account(dest_info);
+        let before_output = token::accessor::amount(output)?;
+
+        // only the final leg carries the caller's min_out
+        let is_last = i + 1 == hops;
+        let hop_min_out = if is_last { min_out } else { 1 };
+
+        let ix = venue.swap_ix(
+            source_info.key,
+            dest_info.key,
+            &vault_key,
+            SwapMode::ExactIn,
+            hop_amount,
+            hop_min_out,
+        )?;
+
+        let mut infos = venue.cpi_accounts();
+        infos.push(source_info.clone());
+        infos.push(dest_info.clone());
+        infos.push(ctx.accounts.vault.clone());
+        infos.push(ctx.accounts.token_program.to_account_info());
+
+        invoke_signed(&ix, &infos, signer_seeds)?;
+
+        hop_amount = token::accessor::amount(output)?.saturating_sub(before_output);
+        require!(hop_amount >= hop_min_out, SolscopeError::SlippageExceeded);
+    }
+
+    /* ================= Post-route checks ================= */
+    let spent = before_source.saturating_sub(token::accessor::amount(&path_infos[0])?);
+    require!(spent <= amount_in, SolscopeError::SlippageExceeded);
+
+    // hop_amount now holds what the final leg delivered (checked against min_out above)
+    let received = hop_amount;
+
+    /* ================= Risk limits (post-trade accounting) ================= */
+    // lamport notional: SOL spent on the way in, or SOL received on the way out
+    let notional = if sol_in {
+        spent
+    } else if sol_out {
+        received
+    } else {
+        0
+    };
+
+    if let Some(risk_config) = ctx.accounts.risk_config.as_mut() {
+        risk_config.record_trade(notional)?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dex::{DEX_METEORA_DLMM, DEX_RAYDIUM_CLMM, DEX_RAYDIUM_CPMM, DEX_RAYDIUM_V4};
+
+    #[test]
+    fn trailing_account_venues_must_be_the_last_hop() {
+        check_hops(&[DEX_RAYDIUM_V4, DEX_METEORA_DLMM]).unwrap();
+        check_hops(&[DEX_RAYDIUM_CPMM, DEX_RAYDIUM_V4, DEX_RAYDIUM_CLMM]).unwrap();
+
+        for dexes in [
+            &[DEX_METEORA_DLMM, DEX_RAYDIUM_V4][..],
+            &[DEX_RAYDIUM_V4, DEX_RAYDIUM_CLMM, DEX_RAYDIUM_CPMM][..],
+            &[DEX_METEORA_DLMM, DEX_METEORA_DLMM][..],
+        ] {
+            let err = check_hops(dexes).unwrap_err();
+            assert_eq!(err, SolscopeError::InvalidRoute.into());
+        }
+    }
+
+    #[test]
+    fn route_length_is_bounded() {
+        assert_eq!(
+            check_hops(&[]).unwrap_err(),
+            SolscopeError::InvalidRoute.into()
+        );
+        assert_eq!(
+            check_hops(&[DEX_RAYDIUM_V4; MAX_ROUTE_HOPS + 1]).unwrap_err(),
+            SolscopeError::InvalidRoute.into()
+        );
+    }
+}
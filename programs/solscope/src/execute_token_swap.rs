@@ -0,0 +1,265 @@
+// programs/solscope/src/execute_token_swap.rs
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program::invoke_signed;
+
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::{self, Mint, Token, TokenAccount},
+};
+use spl_token::native_mint;
+
+use crate::{
+    dex::{self, SwapMode},
+    errors::SolscopeError,
+    execute_trade,
+    guards,
+    state::{
+        BotAction, BotMeta, BotStatus, MintPolicy, OracleConfig, PoolAllowlist, RiskConfig,
+        SessionKey,
+    },
+};
+
+/// Venue accounts for `dex` (see DexAdapter impls) are passed in remaining_accounts
+#[derive(Accounts)]
+#[instruction(bot_id_hash: [u8; 32])]
+pub struct ExecuteTokenSwap<'info> {
+    /// Owner, delegated operator or live session key (pays for vault ATA creation)
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// BotMeta PDA (derived from the stored owner, not the signer)
+    #[account(
+        seeds = [b"bot", bot_meta.owner.as_ref(), &bot_id_hash],
+        bump,
+        has_one = vault
+    )]
+    pub bot_meta: Account<'info, BotMeta>,
+
+    /// CHECK: Vault PDA (authority of both vault ATAs)
+    #[account(
+        seeds = [b"vault", bot_meta.owner.as_ref(), &bot_id_hash],
+        bump
+    )]
+    pub vault: AccountInfo<'info>,
+
+    /// SessionKey PDA (required only when authority is a session key)
+    #[account(
+        mut,
+        seeds = [b"session", bot_meta.key().as_ref(), authority.key().as_ref()],
+        bump = session_key.bump,
+        has_one = bot_meta,
+    )]
+    pub session_key: Option<Account<'info, SessionKey>>,
+
+    /// RiskConfig PDA (required once the owner has configured limits)
+    #[account(
+        mut,
+        seeds = [b"risk", bot_meta.key().as_ref()],
+        bump = risk_config.bump,
+        has_one = bot_meta,
+    )]
+    pub risk_config: Option<Account<'info, RiskConfig>>,
+
+    /// MintPolicy PDA (required once the owner has created one)
+    #[account(
+        seeds = [b"mint_policy", bot_meta.key().as_ref()],
+        bump = mint_policy.bump,
+        has_one = bot_meta,
+    )]
+    pub mint_policy: Option<Account<'info, MintPolicy>>,
+
+    /// PoolAllowlist PDA (required once the owner has created one)
+    #[account(
+        seeds = [b"pools", bot_meta.key().as_ref()],
+        bump = pool_allowlist.bump,
+        has_one = bot_meta,
+    )]
+    pub pool_allowlist: Option<Account<'info, PoolAllowlist>>,
+
+    /// OracleConfig PDA (required once the owner has configured a price band)
+    #[account(
+        seeds = [b"oracle", bot_meta.key().as_ref()],
+        bump = oracle_config.bump,
+        has_one = bot_meta,
+    )]
+    pub oracle_config: Option<Account<'info, OracleConfig>>,
+
+    /// CHECK: Pyth-layout price account named by oracle_config
+    pub oracle: Option<UncheckedAccount<'info>>,
+
+    /// Input token mint (e.g. USDC)
+    pub source_mint: Account<'info, Mint>,
+
+    /// Output token mint (e.g. BONK)
+    #[account(constraint = dest_mint.key() != source_mint.key() @ SolscopeError::InvalidAmount)]
+    pub dest_mint: Account<'info, Mint>,
+
+    /// Vault ATA spending `source_mint`
+    #[account(
+        mut,
+        associated_token::mint = source_mint,
+        associated_token::authority = vault
+    )]
+    pub vault_source_ata: Account<'info, TokenAccount>,
+
+    /// Vault ATA receiving `dest_mint`
+    #[account(
+        init_if_needed,
+        payer = authority,
+        associated_token::mint = dest_mint,
+        associated_token::authority = vault
+    )]
+    pub vault_dest_ata: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, ExecuteTokenSwap<'info>>,
+    bot_id_hash: [u8; 32],
+    dex: u8,
+    amount_in: u64,
+    min_out: u64,
+) -> Result<()> {
+    require!(amount_in > 0, SolscopeError::InvalidAmount);
+    require!(min_out > 0, SolscopeError::InvalidAmount);
+
+    require!(
+        ctx.accounts.bot_meta.bot_id_hash == bot_id_hash,
+        SolscopeError::BotIdMismatch
+    );
+    let source_mint = ctx.accounts.source_mint.key();
+    let dest_mint = ctx.accounts.dest_mint.key();
+    let sol_in = source_mint == native_mint::id();
+    let sol_out = dest_mint == native_mint::id();
+
+    // exiting to SOL, or any swap while liquidating, unwinds a position; the rest open one
+    let action = if sol_out || ctx.accounts.bot_meta.status == BotStatus::Liquidating {
+        BotAction::Sell
+    } else {
+        BotAction::Buy
+    };
+    ctx.accounts.bot_meta.require_allows(action)?;
+
+    // the oracle prices a token in SOL; a token -> token fill has nothing to check against
+    if !sol_in && !sol_out {
+        guards::require_no_oracle(&ctx.accounts.bot_meta)?;
+    }
+
+    /* ================= Signer authorization ================= */
+    guards::authorize_trader(
+        &ctx.accounts.bot_meta,
+        ctx.accounts.authority.key,
+        ctx.accounts.session_key.as_mut(),
+        sol_in.then_some(amount_in),
+    )?;
+
+    /* ================= Risk limits (pre-trade) ================= */
+    // token spend -> per-trade limit of sell_limit_mint; notional is counted on a wSOL leg
+    if let Some(risk_config) =
+        guards::load_risk_config(&ctx.accounts.bot_meta, ctx.accounts.risk_config.as_mut())?
+    {
+        risk_config.check_trade(sol_in, &source_mint, amount_in)?;
+        if !sol_in && !sol_out {
+            risk_config.check_unpriced_trade()?;
+        }
+    }
+
+    /* ================= Mint policy + venue validation ================= */
+    guards::check_mint_policy(
+        &ctx.accounts.bot_meta,
+        ctx.accounts.mint_policy.as_ref(),
+        &[source_mint, dest_mint],
+    )?;
+
+    let mut venue_accounts: &'info [AccountInfo<'info>] = ctx.remaining_accounts;
+    let mut venue = dex::load_venue(dex, &mut venue_accounts)?;
+    require!(venue_accounts.is_empty(), SolscopeError::UnexpectedVenueAccounts);
+
+    guards::check_pool_allowlist(
+        &ctx.accounts.bot_meta,
+        ctx.accounts.pool_allowlist.as_ref(),
+        &venue.pool_id(),
+    )?;
+    venue.validate(&source_mint, &dest_mint)?;
+
+    require!(
+        ctx.accounts.vault_source_ata.amount >= amount_in,
+        SolscopeError::InsufficientVaultFunds
+    );
+
+    /* ================= PDA signer ================= */
+    let owner = ctx.accounts.bot_meta.owner;
+    let vault_seeds: &[&[u8]] = &[
+        b"vault",
+        owner.as_ref(),
+        &bot_id_hash,
+        &[ctx.bumps.vault],
+    ];
+    let signer_seeds = &[vault_seeds];
+
+    /* ================= Pre-swap balance snapshots ================= */
+    let source_info = ctx.accounts.vault_source_ata.to_account_info();
+    let dest_info = ctx.accounts.vault_dest_ata.to_account_info();
+    let output = venue.output_account(&dest_info).clone();
+    let before_source = token::accessor::amount(&source_info)?;
+    let before_output = token::accessor::amount(&output)?;
+
+    /* ================= Venue swap (token -> token) ================= */
+    let ix = venue.swap_ix(
+        source_info.key,
+        dest_info.key,
+        ctx.accounts.vault.key,
+        SwapMode::ExactIn,
+        amount_in,
+        min_out,
+    )?;
+
+    let mut infos = venue.cpi_accounts();
+    infos.push(source_info.clone());
+    infos.push(dest_info.clone());
+    infos.push(ctx.accounts.vault.to_account_info());
+    infos.push(ctx.accounts.token_program.to_account_info());
+
+    invoke_signed(&ix, &infos, signer_seeds)?;
+
+    /* ================= Post-swap delta checks (same as execute_trade) ================= */
+    let (spent, received) = execute_trade::swap_deltas(
+        (before_source, token::accessor::amount(&source_info)?),
+        (before_output, token::accessor::amount(&output)?),
+        amount_in,
+        min_out,
+    )?;
+
+    /* ================= Oracle price band (token -> token was refused above) ================= */
+    if sol_in || sol_out {
+        guards::check_swap_oracle_price(
+            &ctx.accounts.bot_meta,
+            ctx.accounts.oracle_config.as_ref(),
+            ctx.accounts.oracle.as_deref(),
+            &ctx.accounts.source_mint,
+            &ctx.accounts.dest_mint,
+            spent,
+            received,
+        )?;
+    }
+
+    /* ================= Risk limits (post-trade accounting) ================= */
+    // lamport notional: wSOL spent on the way in, or wSOL received on the way out
+    let notional = if sol_in {
+        spent
+    } else if sol_out {
+        received
+    } else {
+        0
+    };
+
+    if let Some(risk_config) = ctx.accounts.risk_config.as_mut() {
+        risk_config.record_trade(notional)?;
+    }
+
+    Ok(())
+}
@@ -1,12 +1,7 @@
 // programs/solscope/src/execute_trade.rs
 
 use anchor_lang::prelude::*;
-use anchor_lang::solana_program::{
-    instruction::{AccountMeta, Instruction},
-    program::invoke_signed,
-    system_instruction,
-};
-use std::str::FromStr;
+use anchor_lang::solana_program::{program::invoke_signed, system_instruction};
 
 use anchor_spl::{
     associated_token::AssociatedToken,
@@ -14,38 +9,28 @@ use anchor_spl::{
 };
 use spl_token::native_mint;
 
-use crate::{errors::SolscopeError, state::BotMeta};
+use crate::{
+    dex::{self, DexAdapter, SwapMode},
+    errors::SolscopeError,
+    guards,
+    state::{BotAction, BotMeta, MintPolicy, OracleConfig, PoolAllowlist, RiskConfig, SessionKey},
+};
 
 pub const SIDE_BUY: u8 = 0;
 pub const SIDE_SELL: u8 = 1;
 
-/* ======================================================
- * Helpers
- * ====================================================== */
-
-fn raydium_amm_program() -> Pubkey {
-    Pubkey::from_str("RVKd61ztZW9KQqkHn7kYk9Z3n5Vf3L7hPwrKyYVJZZz").unwrap()
-}
-
-fn raydium_swap_base_in_data(amount_in: u64, min_out: u64) -> Vec<u8> {
-    // Raydium v4 SwapBaseIn (commonly 9). You already used this.
-    let mut data = Vec::with_capacity(17);
-    data.push(9);
-    data.extend_from_slice(&amount_in.to_le_bytes());
-    data.extend_from_slice(&min_out.to_le_bytes());
-    data
-}
-
+/// Venue accounts for `dex` (see DexAdapter impls) are passed in remaining_accounts
 #[derive(Accounts)]
 #[instruction(bot_id_hash: [u8; 32])]
 pub struct ExecuteTrade<'info> {
+    /// Owner, delegated operator or live session key (pays for vault ATA creation)
     #[account(mut)]
-    pub owner: Signer<'info>,
+    pub authority: Signer<'info>,
 
+    /// BotMeta PDA (derived from the stored owner, not the signer)
     #[account(
-        seeds = [b"bot", owner.key().as_ref(), &bot_id_hash],
+        seeds = [b"bot", bot_meta.owner.as_ref(), &bot_id_hash],
         bump,
-        has_one = owner,
         has_one = vault
     )]
     pub bot_meta: Account<'info, BotMeta>,
@@ -53,11 +38,56 @@ pub struct ExecuteTrade<'info> {
     /// CHECK: Vault PDA (system-owned SOL vault)
     #[account(
         mut,
-        seeds = [b"vault", owner.key().as_ref(), &bot_id_hash],
+        seeds = [b"vault", bot_meta.owner.as_ref(), &bot_id_hash],
         bump
     )]
     pub vault: AccountInfo<'info>,
 
+    /// SessionKey PDA (required only when authority is a session key)
+    #[account(
+        mut,
+        seeds = [b"session", bot_meta.key().as_ref(), authority.key().as_ref()],
+        bump = session_key.bump,
+        has_one = bot_meta,
+    )]
+    pub session_key: Option<Account<'info, SessionKey>>,
+
+    /// RiskConfig PDA (required once the owner has configured limits)
+    #[account(
+        mut,
+        seeds = [b"risk", bot_meta.key().as_ref()],
+        bump = risk_config.bump,
+        has_one = bot_meta,
+    )]
+    pub risk_config: Option<Account<'info, RiskConfig>>,
+
+    /// MintPolicy PDA (required once the owner has created one)
+    #[account(
+        seeds = [b"mint_policy", bot_meta.key().as_ref()],
+        bump = mint_policy.bump,
+        has_one = bot_meta,
+    )]
+    pub mint_policy: Option<Account<'info, MintPolicy>>,
+
+    /// PoolAllowlist PDA (required once the owner has created one)
+    #[account(
+        seeds = [b"pools", bot_meta.key().as_ref()],
+        bump = pool_allowlist.bump,
+        has_one = bot_meta,
+    )]
+    pub pool_allowlist: Option<Account<'info, PoolAllowlist>>,
+
+    /// OracleConfig PDA (required once the owner has configured a price band)
+    #[account(
+        seeds = [b"oracle", bot_meta.key().as_ref()],
+        bump = oracle_config.bump,
+        has_one = bot_meta,
+    )]
+    pub oracle_config: Option<Account<'info, OracleConfig>>,
+
+    /// CHECK: Pyth-layout price account named by oracle_config
+    pub oracle: Option<UncheckedAccount<'info>>,
+
     /// Output/Input token mint (depends on side)
     pub mint: Account<'info, Mint>,
 
@@ -66,7 +96,7 @@ pub struct ExecuteTrade<'info> {
     /// SELL: source (spends tokens)
     #[account(
         init_if_needed,
-        payer = owner,
+        payer = authority,
         associated_token::mint = mint,
         associated_token::authority = vault
     )]
@@ -81,59 +111,81 @@ pub struct ExecuteTrade<'info> {
     #[account(mut)]
     pub vault_wsol: AccountInfo<'info>,
 
-    /* ========== Raydium Accounts ========== */
-    /// CHECK: Raydium AMM program
-    #[account(address = raydium_amm_program())]
-    pub amm_program: AccountInfo<'info>,
-
-    /// CHECK: AMM state
-    #[account(mut)]
-    pub amm: AccountInfo<'info>,
-    /// CHECK: AMM authority
-    pub amm_authority: AccountInfo<'info>,
-    /// CHECK: OpenOrders
-    #[account(mut)]
-    pub amm_open_orders: AccountInfo<'info>,
-    /// CHECK: TargetOrders
-    #[account(mut)]
-    pub amm_target_orders: AccountInfo<'info>,
-    /// CHECK: Pool coin (wSOL) vault
-    #[account(mut)]
-    pub pool_coin_token_account: AccountInfo<'info>,
-    /// CHECK: Pool pc (token) vault
-    #[account(mut)]
-    pub pool_pc_token_account: AccountInfo<'info>,
-
-    /// CHECK: Serum market
-    pub serum_market: AccountInfo<'info>,
-    /// CHECK: Serum bids
-    #[account(mut)]
-    pub serum_bids: AccountInfo<'info>,
-    /// CHECK: Serum asks
-    #[account(mut)]
-    pub serum_asks: AccountInfo<'info>,
-    /// CHECK: Serum event queue
-    #[account(mut)]
-    pub serum_event_queue: AccountInfo<'info>,
-    /// CHECK: Serum coin vault
-    #[account(mut)]
-    pub serum_coin_vault: AccountInfo<'info>,
-    /// CHECK: Serum pc vault
-    #[account(mut)]
-    pub serum_pc_vault: AccountInfo<'info>,
-    /// CHECK: Serum vault signer
-    pub serum_vault_signer: AccountInfo<'info>,
-
     pub token_program: Program<'info, Token>,
     pub associated_token_program: Program<'info, AssociatedToken>,
     pub system_program: Program<'info, System>,
     pub rent: Sysvar<'info, Rent>,
 }
 
-pub fn handler(
-    ctx: Context<ExecuteTrade>,
+pub fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, ExecuteTrade<'info>>,
+    bot_id_hash: [u8; 32],
+    dex: u8,
+    side: u8,
+    amount_in: u64,
+    min_out: u64,
+) -> Result<()> {
+    let venue = load_venue(&ctx, dex)?;
+    execute(ctx, bot_id_hash, venue, side, SwapMode::ExactIn, amount_in, min_out)
+}
+
+/// Exact-output trade: `max_amount_in` caps the spend, `amount_out` is the target
+pub fn handler_exact_out<'info>(
+    ctx: Context<'_, '_, 'info, 'info, ExecuteTrade<'info>>,
+    bot_id_hash: [u8; 32],
+    dex: u8,
+    side: u8,
+    max_amount_in: u64,
+    amount_out: u64,
+) -> Result<()> {
+    let venue = load_venue(&ctx, dex)?;
+    execute(ctx, bot_id_hash, venue, side, SwapMode::ExactOut, max_amount_in, amount_out)
+}
+
+/// Venue for `dex` from remaining_accounts (nothing may follow its accounts)
+fn load_venue<'info>(
+    ctx: &Context<'_, '_, 'info, 'info, ExecuteTrade<'info>>,
+    dex: u8,
+) -> Result<Box<dyn DexAdapter<'info> + 'info>> {
+    let mut venue_accounts: &'info [AccountInfo<'info>] = ctx.remaining_accounts;
+    let venue = dex::load_venue(dex, &mut venue_accounts)?;
+    require!(venue_accounts.is_empty(), SolscopeError::UnexpectedVenueAccounts);
+    Ok(venue)
+}
+
+/// (input, output) mints of a trade: wSOL -> mint on BUY, mint -> wSOL on SELL
+pub fn swap_mints(side: u8, mint: &Pubkey) -> (Pubkey, Pubkey) {
+    if side == SIDE_BUY {
+        (native_mint::id(), *mint)
+    } else {
+        (*mint, native_mint::id())
+    }
+}
+
+/// (spent, received) from pre/post balances; the vault never spends more than
+/// `amount_in` and must receive at least `min_out`
+pub fn swap_deltas(
+    (before_source, after_source): (u64, u64),
+    (before_output, after_output): (u64, u64),
+    amount_in: u64,
+    min_out: u64,
+) -> Result<(u64, u64)> {
+    let spent = before_source.saturating_sub(after_source);
+    require!(spent <= amount_in, SolscopeError::SlippageExceeded);
+
+    let received = after_output.saturating_sub(before_output);
+    require!(received >= min_out, SolscopeError::SlippageExceeded);
+    Ok((spent, received))
+}
+
+/// Shared SOL<->token trade path. `amount_in` is the max spend and `min_out`
+/// the minimum received in both modes; only the venue instruction differs.
+pub(crate) fn execute<'info>(
+    ctx: Context<'_, '_, 'info, 'info, ExecuteTrade<'info>>,
     bot_id_hash: [u8; 32],
+    mut venue: Box<dyn DexAdapter<'info> + 'info>,
     side: u8,
+    mode: SwapMode,
     amount_in: u64,
     min_out: u64,
 ) -> Result<()> {
@@ -148,7 +200,47 @@ pub fn handler(
         ctx.accounts.bot_meta.bot_id_hash == bot_id_hash,
         SolscopeError::BotIdMismatch
     );
-    require!(!ctx.accounts.bot_meta.paused, SolscopeError::BotPaused);
+    // SELL stays open while liquidating / new positions are paused
+    let action = if side == SIDE_BUY {
+        BotAction::Buy
+    } else {
+        BotAction::Sell
+    };
+    ctx.accounts.bot_meta.require_allows(action)?;
+
+    /* ================= Signer authorization ================= */
+    let lamports_in = (side == SIDE_BUY).then_some(amount_in);
+    guards::authorize_trader(
+        &ctx.accounts.bot_meta,
+        ctx.accounts.authority.key,
+        ctx.accounts.session_key.as_mut(),
+        lamports_in,
+    )?;
+
+    /* ================= Risk limits (pre-trade) ================= */
+    if let Some(risk_config) =
+        guards::load_risk_config(&ctx.accounts.bot_meta, ctx.accounts.risk_config.as_mut())?
+    {
+        risk_config.check_trade(side == SIDE_BUY, &ctx.accounts.mint.key(), amount_in)?;
+    }
+
+    /* ================= Mint policy (before any lamports leave the vault) ================= */
+    guards::check_mint_policy(
+        &ctx.accounts.bot_meta,
+        ctx.accounts.mint_policy.as_ref(),
+        &[ctx.accounts.mint.key()],
+    )?;
+
+    /* ================= Venue validation (no fake pools) ================= */
+    guards::check_pool_allowlist(
+        &ctx.accounts.bot_meta,
+        ctx.accounts.pool_allowlist.as_ref(),
+        &venue.pool_id(),
+    )?;
+
+    // pool must swap wSOL -> mint (BUY) or mint -> wSOL (SELL)
+    let (input_mint, output_mint) = swap_mints(side, &ctx.accounts.mint.key());
+    venue.validate(&input_mint, &output_mint)?;
 
     // client must provide a fresh Keypair for vault_wsol and sign the tx
     require!(
@@ -163,22 +255,20 @@ pub fn handler(
     );
 
     /* ================= PDA signer ================= */
+    let owner = ctx.accounts.bot_meta.owner;
     let vault_seeds: &[&[u8]] = &[
         b"vault",
-        ctx.accounts.owner.key.as_ref(),
+        owner.as_ref(),
         &bot_id_hash,
         &[ctx.bumps.vault],
     ];
     let signer_seeds = &[vault_seeds];
 
-    /* ================= Pre-swap balance snapshots (extra slippage guard) ================= */
-    let before_token = token::accessor::amount(&ctx.accounts.vault_ata.to_account_info())?;
-    // vault_wsol isn't initialized yet, so "before" is 0 for wsol.
-
     /* ================= Create + init temp wSOL token account ================= */
     let rent_min = ctx.accounts.rent.minimum_balance(TokenAccount::LEN);
 
     // BUY spends SOL from vault into wSOL -> fund with amount_in + rent
+    //      (ExactOut: amount_in is max_amount_in; unspent wSOL is refunded on close)
     // SELL doesn't spend SOL; wSOL is destination -> fund with rent only
     let lamports = if side == SIDE_BUY {
         rent_min.checked_add(amount_in).unwrap()
@@ -223,8 +313,8 @@ pub fn handler(
         },
     ))?;
 
-    /* ================= Raydium swap =================
-     * We keep SwapBaseIn for both directions by swapping user source/dest accounts.
+    /* ================= Venue swap =================
+     * Same venue instruction for both directions; only user source/dest swap.
      * BUY: source = wSOL, dest = token
      * SELL: source = token, dest = wSOL
      */
@@ -239,41 +329,58 @@ pub fn handler(
             ctx.accounts.vault_wsol.to_account_info(),
         )
     };
+    /* ================= Pre-swap balance snapshots (extra slippage guard) ================= */
+    // the venue names the account its output lands in (the user dest for token venues)
+    let output = venue.output_account(&user_dest).clone();
+    let before_source = token::accessor::amount(&user_source)?;
+    let before_output = token::accessor::amount(&output)?;
+
+    let ix = venue.swap_ix(
+        user_source.key,
+        user_dest.key,
+        ctx.accounts.vault.key,
+        mode,
+        amount_in,
+        min_out,
+    )?;
 
-    let ix = Instruction {
-        program_id: ctx.accounts.amm_program.key(),
-        accounts: vec![
-            AccountMeta::new(*ctx.accounts.amm.key, false),
-            AccountMeta::new_readonly(*ctx.accounts.amm_authority.key, false),
-            AccountMeta::new(*ctx.accounts.amm_open_orders.key, false),
-            AccountMeta::new(*ctx.accounts.amm_target_orders.key, false),
-            AccountMeta::new(*ctx.accounts.pool_coin_token_account.key, false),
-            AccountMeta::new(*ctx.accounts.pool_pc_token_account.key, false),
-            AccountMeta::new(*user_source.key, false),
-            AccountMeta::new(*user_dest.key, false),
-            AccountMeta::new_readonly(*ctx.accounts.serum_market.key, false),
-            AccountMeta::new(*ctx.accounts.serum_bids.key, false),
-            AccountMeta::new(*ctx.accounts.serum_asks.key, false),
-            AccountMeta::new(*ctx.accounts.serum_event_queue.key, false),
-            AccountMeta::new(*ctx.accounts.serum_coin_vault.key, false),
-            AccountMeta::new(*ctx.accounts.serum_pc_vault.key, false),
-            AccountMeta::new_readonly(*ctx.accounts.serum_vault_signer.key, false),
-            AccountMeta::new_readonly(ctx.accounts.token_program.key(), false),
-        ],
-        data: raydium_swap_base_in_data(amount_in, min_out),
-    };
+    let mut infos = venue.cpi_accounts();
+    infos.push(user_source.clone());
+    infos.push(user_dest.clone());
+    infos.push(ctx.accounts.vault.to_account_info());
+    infos.push(ctx.accounts.token_program.to_account_info());
 
-    invoke_signed(&ix, &ctx.accounts.to_account_infos(), signer_seeds)?;
+    invoke_signed(&ix, &infos, signer_seeds)?;
 
     /* ================= Post-swap delta checks (extra slippage protection) ================= */
-    if side == SIDE_BUY {
-        let after_token = token::accessor::amount(&ctx.accounts.vault_ata.to_account_info())?;
-        let received = after_token.saturating_sub(before_token);
-        require!(received >= min_out, SolscopeError::SlippageExceeded);
-    } else {
-        // SELL: output is wSOL -> check wSOL token amount >= min_out
-        let after_wsol = token::accessor::amount(&ctx.accounts.vault_wsol.to_account_info())?;
-        require!(after_wsol >= min_out, SolscopeError::SlippageExceeded);
+    // amount_in is max_amount_in for ExactOut
+    // BUY: tokens into vault_ata; SELL: wSOL into the fresh temp account
+    let (spent, received) = swap_deltas(
+        (before_source, token::accessor::amount(&user_source)?),
+        (before_output, token::accessor::amount(&output)?),
+        amount_in,
+        min_out,
+    )?;
+
+    // lamport notional of this trade (BUY: SOL spent, SELL: SOL received)
+    let notional = if side == SIDE_BUY { spent } else { received };
+
+    /* ================= Oracle price band (min_out alone can't catch a bad quote) ================= */
+    let token_amount = if side == SIDE_BUY { received } else { spent };
+    guards::check_oracle_price(
+        &ctx.accounts.bot_meta,
+        ctx.accounts.oracle_config.as_ref(),
+        ctx.accounts.oracle.as_deref(),
+        &ctx.accounts.mint.key(),
+        side == SIDE_BUY,
+        notional,
+        token_amount,
+        ctx.accounts.mint.decimals,
+    )?;
+
+    /* ================= Risk limits (post-trade accounting) ================= */
+    if let Some(risk_config) = ctx.accounts.risk_config.as_mut() {
+        risk_config.record_trade(notional)?;
     }
 
     /* ================= Close wSOL (unwrap) =================
@@ -292,3 +399,39 @@ pub fn handler(
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn swap_mints_put_wsol_on_the_lamport_side() {
+        let mint = Pubkey::new_unique();
+        assert_eq!(swap_mints(SIDE_BUY, &mint), (native_mint::id(), mint));
+        assert_eq!(swap_mints(SIDE_SELL, &mint), (mint, native_mint::id()));
+    }
+
+    #[test]
+    fn swap_deltas_accept_fills_within_limits() {
+        // spent exactly amount_in, received more than min_out
+        let (spent, received) = swap_deltas((5_000, 4_000), (10, 260), 1_000, 200).unwrap();
+        assert_eq!((spent, received), (1_000, 250));
+
+        // ExactOut refund: spent less than max_amount_in
+        let (spent, received) = swap_deltas((5_000, 4_400), (0, 200), 1_000, 200).unwrap();
+        assert_eq!((spent, received), (600, 200));
+    }
+
+    #[test]
+    fn swap_deltas_reject_overspend_and_short_output() {
+        let err = swap_deltas((5_000, 3_999), (0, 500), 1_000, 200).unwrap_err();
+        assert_eq!(err, SolscopeError::SlippageExceeded.into());
+
+        let err = swap_deltas((5_000, 4_000), (100, 299), 1_000, 200).unwrap_err();
+        assert_eq!(err, SolscopeError::SlippageExceeded.into());
+
+        // an output account that shrank counts as nothing received
+        let err = swap_deltas((5_000, 4_000), (500, 400), 1_000, 1).unwrap_err();
+        assert_eq!(err, SolscopeError::SlippageExceeded.into());
+    }
+}
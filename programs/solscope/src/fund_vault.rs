@@ -4,7 +4,7 @@ use anchor_lang::prelude::*;
 use anchor_lang::system_program;
 
 use crate::errors::SolscopeError;
-use crate::state::BotMeta;
+use crate::state::{BotAction, BotMeta};
 
 #[derive(Accounts)]
 #[instruction(bot_id_hash: [u8; 32])]
@@ -44,12 +44,12 @@ pub struct FundVault<'info> {
 pub fn handler(ctx: Context<FundVault>, bot_id_hash: [u8; 32], amount: u64) -> Result<()> {
     require!(amount > 0, SolscopeError::InvalidAmount);
 
-    // extra safety: bot_id must match + not paused
+    // extra safety: bot_id must match + status/flags allow funding
     require!(
         ctx.accounts.bot_meta.bot_id_hash == bot_id_hash,
         SolscopeError::BotIdMismatch
     );
-    require!(!ctx.accounts.bot_meta.paused, SolscopeError::BotPaused);
+    ctx.accounts.bot_meta.require_allows(BotAction::Fund)?;
 
     let cpi_ctx = CpiContext::new(
         ctx.accounts.system_program.to_account_info(),
@@ -0,0 +1,89 @@
+// programs/solscope/src/fund_vault_token.rs
+
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::{self, Mint, Token, TokenAccount, TransferChecked},
+};
+
+use crate::errors::SolscopeError;
+use crate::state::{BotAction, BotMeta};
+
+#[derive(Accounts)]
+#[instruction(bot_id_hash: [u8; 32])]
+pub struct FundVaultToken<'info> {
+    /// Bot owner funding the vault
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    /// BotMeta PDA (must link owner + vault)
+    #[account(
+        seeds = [
+            b"bot",
+            owner.key().as_ref(),
+            &bot_id_hash,
+        ],
+        bump,
+        has_one = owner,
+        has_one = vault
+    )]
+    pub bot_meta: Account<'info, BotMeta>,
+
+    /// CHECK: Vault PDA (authority of the vault ATA)
+    #[account(
+        seeds = [
+            b"vault",
+            owner.key().as_ref(),
+            &bot_id_hash,
+        ],
+        bump
+    )]
+    pub vault: AccountInfo<'info>,
+
+    pub mint: Account<'info, Mint>,
+
+    /// Owner token account (source)
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = owner
+    )]
+    pub owner_ata: Account<'info, TokenAccount>,
+
+    /// Vault ATA for this mint (destination)
+    #[account(
+        init_if_needed,
+        payer = owner,
+        associated_token::mint = mint,
+        associated_token::authority = vault
+    )]
+    pub vault_ata: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<FundVaultToken>, bot_id_hash: [u8; 32], amount: u64) -> Result<()> {
+    require!(amount > 0, SolscopeError::InvalidAmount);
+
+    // extra safety: bot_id must match + status/flags allow funding
+    require!(
+        ctx.accounts.bot_meta.bot_id_hash == bot_id_hash,
+        SolscopeError::BotIdMismatch
+    );
+    ctx.accounts.bot_meta.require_allows(BotAction::Fund)?;
+
+    let cpi_ctx = CpiContext::new(
+        ctx.accounts.token_program.to_account_info(),
+        TransferChecked {
+            from: ctx.accounts.owner_ata.to_account_info(),
+            mint: ctx.accounts.mint.to_account_info(),
+            to: ctx.accounts.vault_ata.to_account_info(),
+            authority: ctx.accounts.owner.to_account_info(),
+        },
+    );
+
+    token::transfer_checked(cpi_ctx, amount, ctx.accounts.mint.decimals)?;
+    Ok(())
+}
@@ -0,0 +1,368 @@
+// programs/solscope/src/guards.rs
+
+use anchor_lang::prelude::*;
+
+use anchor_spl::token::Mint;
+use spl_token::native_mint;
+
+use crate::errors::SolscopeError;
+use crate::pyth::PythPriceHeader;
+use crate::state::{BotMeta, MintPolicy, OracleConfig, PoolAllowlist, RiskConfig, SessionKey};
+
+/* ======================================================
+ * Shared pre-trade guards (used by every trade path)
+ * ====================================================== */
+
+/// Owner/operator always pass; anyone else must be a live session key.
+/// `lamports_in` is the SOL the trade may spend (BUY), charged to the session budget.
+pub fn authorize_trader(
+    bot_meta: &BotMeta,
+    authority: &Pubkey,
+    session_key: Option<&mut Account<SessionKey>>,
+    lamports_in: Option<u64>,
+) -> Result<()> {
+    if bot_meta.can_trade(authority) {
+        return Ok(());
+    }
+
+    let session_key = session_key.ok_or(SolscopeError::Unauthorized)?;
+    require!(session_key.key == *authority, SolscopeError::Unauthorized);
+    require!(
+        Clock::get()?.unix_timestamp < session_key.expires_at,
+        SolscopeError::SessionKeyExpired
+    );
+
+    // only BUY spends vault lamports; SELL is always allowed to exit
+    if let Some(amount_in) = lamports_in {
+        require!(
+            amount_in <= session_key.max_lamports_per_trade,
+            SolscopeError::SessionTradeLimitExceeded
+        );
+        session_key.remaining_budget = session_key
+            .remaining_budget
+            .checked_sub(amount_in)
+            .ok_or(SolscopeError::SessionBudgetExhausted)?;
+    }
+    Ok(())
+}
+
+/// RiskConfig must be passed once configured; rolls the UTC day before use
+pub fn load_risk_config<'a, 'info>(
+    bot_meta: &BotMeta,
+    risk_config: Option<&'a mut Account<'info, RiskConfig>>,
+) -> Result<Option<&'a mut Account<'info, RiskConfig>>> {
+    if bot_meta.risk_config.is_none() {
+        return Ok(None);
+    }
+
+    let risk_config = risk_config.ok_or(SolscopeError::RiskConfigMissing)?;
+    risk_config.roll_day(Clock::get()?.unix_timestamp);
+    Ok(Some(risk_config))
+}
+
+/// Every mint the trade touches must pass the bot's MintPolicy (if any)
+pub fn check_mint_policy(
+    bot_meta: &BotMeta,
+    mint_policy: Option<&Account<MintPolicy>>,
+    mints: &[Pubkey],
+) -> Result<()> {
+    if bot_meta.mint_policy.is_none() {
+        return Ok(());
+    }
+
+    let mint_policy = mint_policy.ok_or(SolscopeError::MintPolicyMissing)?;
+    for mint in mints {
+        require!(mint_policy.allows(mint), SolscopeError::MintNotAllowed);
+    }
+    Ok(())
+}
+
+/// Pool must be on the bot's PoolAllowlist (if any)
+pub fn check_pool_allowlist(
+    bot_meta: &BotMeta,
+    pool_allowlist: Option<&Account<PoolAllowlist>>,
+    pool: &Pubkey,
+) -> Result<()> {
+    if bot_meta.pool_allowlist.is_none() {
+        return Ok(());
+    }
+
+    let pool_allowlist = pool_allowlist.ok_or(SolscopeError::PoolAllowlistMissing)?;
+    require!(
+        pool_allowlist.amms.contains(pool),
+        SolscopeError::PoolNotAllowed
+    );
+    Ok(())
+}
+
+/// Fill price must sit inside the bot's oracle band (if any). `lamports` and
+/// `token_amount` are the two legs of the fill of `mint`; only adverse
+/// deviation fails (BUY paid too much, SELL received too little).
+#[allow(clippy::too_many_arguments)]
+pub fn check_oracle_price(
+    bot_meta: &BotMeta,
+    oracle_config: Option<&Account<OracleConfig>>,
+    oracle: Option<&AccountInfo>,
+    mint: &Pubkey,
+    is_buy: bool,
+    lamports: u64,
+    token_amount: u64,
+    token_decimals: u8,
+) -> Result<()> {
+    if bot_meta.oracle_config.is_none() {
+        return Ok(());
+    }
+
+    let oracle_config = oracle_config.ok_or(SolscopeError::OracleConfigMissing)?;
+    let oracle = oracle.ok_or(SolscopeError::OracleConfigMissing)?;
+    check_fill_price(
+        oracle_config,
+        oracle,
+        mint,
+        is_buy,
+        lamports,
+        token_amount,
+        token_decimals,
+        Clock::get()?.unix_timestamp,
+    )
+}
+
+/// check_oracle_price for a swap with wSOL on one end; the other end is the priced token
+pub fn check_swap_oracle_price<'info>(
+    bot_meta: &BotMeta,
+    oracle_config: Option<&Account<OracleConfig>>,
+    oracle: Option<&AccountInfo>,
+    source_mint: &Account<'info, Mint>,
+    dest_mint: &Account<'info, Mint>,
+    spent: u64,
+    received: u64,
+) -> Result<()> {
+    let is_buy = source_mint.key() == native_mint::id();
+    let (token_mint, lamports, token_amount) = if is_buy {
+        (dest_mint, spent, received)
+    } else {
+        (source_mint, received, spent)
+    };
+    check_oracle_price(
+        bot_meta,
+        oracle_config,
+        oracle,
+        &token_mint.key(),
+        is_buy,
+        lamports,
+        token_amount,
+        token_mint.decimals,
+    )
+}
+
+/// Paths that can't price their fill in SOL are closed once the bot has an oracle band
+pub fn require_no_oracle(bot_meta: &BotMeta) -> Result<()> {
+    require!(
+        bot_meta.oracle_config.is_none(),
+        SolscopeError::OracleUncheckedPath
+    );
+    Ok(())
+}
+
+/// check_oracle_price against an explicit config and clock
+#[allow(clippy::too_many_arguments)]
+pub fn check_fill_price(
+    oracle_config: &OracleConfig,
+    oracle: &AccountInfo,
+    mint: &Pubkey,
+    is_buy: bool,
+    lamports: u64,
+    token_amount: u64,
+    token_decimals: u8,
+    now: i64,
+) -> Result<()> {
+    // the configured feed prices one mint; it says nothing about any other
+    require!(
+        *oracle.key == oracle_config.oracle && *mint == oracle_config.mint,
+        SolscopeError::InvalidOracleAccount
+    );
+
+    let (price, exponent) =
+        PythPriceHeader::load(oracle)?.current_price(now, oracle_config.max_staleness_secs)?;
+
+    // fair lamports = token_amount * price * 10^(exponent + 9 - token_decimals);
+    // the power of ten goes on whichever side keeps it an integer
+    let scale = exponent + i32::from(native_mint::DECIMALS) - i32::from(token_decimals);
+    let pow10 = 10u128.checked_pow(scale.unsigned_abs());
+    let (lamports_scale, fair_scale) = if scale >= 0 {
+        (Some(1), pow10)
+    } else {
+        (pow10, Some(1))
+    };
+
+    let bps = u128::from(oracle_config.max_deviation_bps);
+    let band = if is_buy {
+        OracleConfig::BPS_DENOMINATOR + bps
+    } else {
+        OracleConfig::BPS_DENOMINATOR - bps
+    };
+
+    let paid = lamports_scale
+        .and_then(|s| u128::from(lamports).checked_mul(s))
+        .and_then(|v| v.checked_mul(OracleConfig::BPS_DENOMINATOR));
+    let fair = fair_scale
+        .and_then(|s| u128::from(token_amount).checked_mul(price as u128)?.checked_mul(s))
+        .and_then(|v| v.checked_mul(band));
+    let (paid, fair) = paid
+        .zip(fair)
+        .ok_or(SolscopeError::OraclePriceUnavailable)?;
+
+    if is_buy {
+        require!(paid <= fair, SolscopeError::OracleDeviationExceeded);
+    } else {
+        require!(paid >= fair, SolscopeError::OracleDeviationExceeded);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pyth::{pyth_oracle_program, PYTH_PRICE_HEADER_LEN};
+    use crate::test_utils::{put, TestAccount};
+
+    const NOW: i64 = 1_700_000_000;
+
+    /// 0.05 SOL per whole token (5_000_000 * 10^-8), published at `timestamp`
+    fn pyth_price_data(timestamp: i64) -> Vec<u8> {
+        let mut data = vec![0u8; PYTH_PRICE_HEADER_LEN];
+        put(&mut data, 0, &0xa1b2_c3d4u32.to_le_bytes());
+        put(&mut data, 4, &2u32.to_le_bytes());
+        put(&mut data, 8, &3u32.to_le_bytes());
+        put(&mut data, 20, &(-8i32).to_le_bytes());
+        put(&mut data, 96, &timestamp.to_le_bytes());
+        put(&mut data, 208, &5_000_000i64.to_le_bytes());
+        put(&mut data, 224, &1u32.to_le_bytes());
+        data
+    }
+
+    fn oracle_config(oracle: &Pubkey, mint: &Pubkey) -> OracleConfig {
+        OracleConfig {
+            bot_meta: Pubkey::new_unique(),
+            oracle: *oracle,
+            mint: *mint,
+            max_deviation_bps: 100,
+            max_staleness_secs: 60,
+            bump: 255,
+        }
+    }
+
+    /// 1_000 tokens (6 decimals) at 0.05 SOL is 50 SOL fair; the band is +-1%
+    fn check(
+        config: &OracleConfig,
+        oracle: &mut TestAccount,
+        is_buy: bool,
+        lamports: u64,
+    ) -> Result<()> {
+        check_fill_price(
+            config,
+            &oracle.info(),
+            &config.mint,
+            is_buy,
+            lamports,
+            1_000_000_000,
+            6,
+            NOW,
+        )
+    }
+
+    #[test]
+    fn fills_inside_the_band_pass() {
+        let mut oracle = TestAccount::new(pyth_oracle_program(), pyth_price_data(NOW - 10));
+        let config = oracle_config(&oracle.key, &Pubkey::new_unique());
+
+        check(&config, &mut oracle, true, 50_000_000_000).unwrap();
+        check(&config, &mut oracle, true, 50_500_000_000).unwrap();
+        check(&config, &mut oracle, false, 49_600_000_000).unwrap();
+        check(&config, &mut oracle, false, 49_500_000_000).unwrap();
+        // favourable fills are never rejected
+        check(&config, &mut oracle, true, 1).unwrap();
+        check(&config, &mut oracle, false, 80_000_000_000).unwrap();
+    }
+
+    #[test]
+    fn adverse_fills_outside_the_band_fail() {
+        let mut oracle = TestAccount::new(pyth_oracle_program(), pyth_price_data(NOW - 10));
+        let config = oracle_config(&oracle.key, &Pubkey::new_unique());
+
+        assert_eq!(
+            check(&config, &mut oracle, true, 51_000_000_000).unwrap_err(),
+            SolscopeError::OracleDeviationExceeded.into()
+        );
+        assert_eq!(
+            check(&config, &mut oracle, false, 49_000_000_000).unwrap_err(),
+            SolscopeError::OracleDeviationExceeded.into()
+        );
+    }
+
+    #[test]
+    fn stale_or_halted_price_is_unavailable() {
+        let mut stale = TestAccount::new(pyth_oracle_program(), pyth_price_data(NOW - 61));
+        let config = oracle_config(&stale.key, &Pubkey::new_unique());
+        assert_eq!(
+            check(&config, &mut stale, true, 50_000_000_000).unwrap_err(),
+            SolscopeError::OraclePriceUnavailable.into()
+        );
+
+        let mut data = pyth_price_data(NOW);
+        put(&mut data, 224, &2u32.to_le_bytes());
+        let mut halted = TestAccount::new(pyth_oracle_program(), data);
+        let config = oracle_config(&halted.key, &Pubkey::new_unique());
+        assert_eq!(
+            check(&config, &mut halted, true, 50_000_000_000).unwrap_err(),
+            SolscopeError::OraclePriceUnavailable.into()
+        );
+    }
+
+    #[test]
+    fn non_pyth_accounts_are_rejected() {
+        for (offset, value) in [(0, 0xdead_beefu32), (4, 1)] {
+            let mut data = pyth_price_data(NOW);
+            put(&mut data, offset, &value.to_le_bytes());
+            let mut oracle = TestAccount::new(pyth_oracle_program(), data);
+            let config = oracle_config(&oracle.key, &Pubkey::new_unique());
+            assert_eq!(
+                check(&config, &mut oracle, true, 50_000_000_000).unwrap_err(),
+                SolscopeError::InvalidOracleAccount.into()
+            );
+        }
+
+        // right layout, but not owned by the Pyth oracle program
+        let mut oracle = TestAccount::new(Pubkey::new_unique(), pyth_price_data(NOW));
+        let config = oracle_config(&oracle.key, &Pubkey::new_unique());
+        assert_eq!(
+            check(&config, &mut oracle, true, 50_000_000_000).unwrap_err(),
+            SolscopeError::InvalidOracleAccount.into()
+        );
+    }
+
+    #[test]
+    fn oracle_must_be_the_configured_feed_for_the_traded_mint() {
+        let mut oracle = TestAccount::new(pyth_oracle_program(), pyth_price_data(NOW));
+        let config = oracle_config(&Pubkey::new_unique(), &Pubkey::new_unique());
+        assert_eq!(
+            check(&config, &mut oracle, true, 50_000_000_000).unwrap_err(),
+            SolscopeError::InvalidOracleAccount.into()
+        );
+
+        // right feed, but the fill is of a different token than it prices
+        let config = oracle_config(&oracle.key, &Pubkey::new_unique());
+        let err = check_fill_price(
+            &config,
+            &oracle.info(),
+            &Pubkey::new_unique(),
+            true,
+            50_000_000_000,
+            1_000_000_000,
+            6,
+            NOW,
+        )
+        .unwrap_err();
+        assert_eq!(err, SolscopeError::InvalidOracleAccount.into());
+    }
+}
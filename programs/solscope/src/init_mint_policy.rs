@@ -0,0 +1,50 @@
+// programs/solscope/src/init_mint_policy.rs
+
+use anchor_lang::prelude::*;
+
+use crate::state::{BotMeta, MintPolicy, MintPolicyMode};
+
+#[derive(Accounts)]
+#[instruction(bot_id_hash: [u8; 32])]
+pub struct InitMintPolicy<'info> {
+    /// Bot owner (pays for the MintPolicy PDA)
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"bot", owner.key().as_ref(), &bot_id_hash],
+        bump,
+        has_one = owner,
+    )]
+    pub bot_meta: Account<'info, BotMeta>,
+
+    /// MintPolicy PDA (1 per bot)
+    #[account(
+        init,
+        payer = owner,
+        space = 8 + MintPolicy::LEN,
+        seeds = [b"mint_policy", bot_meta.key().as_ref()],
+        bump
+    )]
+    pub mint_policy: Account<'info, MintPolicy>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(
+    ctx: Context<InitMintPolicy>,
+    _bot_id_hash: [u8; 32],
+    mode: MintPolicyMode,
+) -> Result<()> {
+    let mint_policy = &mut ctx.accounts.mint_policy;
+
+    mint_policy.bot_meta = ctx.accounts.bot_meta.key();
+    mint_policy.mode = mode;
+    mint_policy.mints = Vec::new();
+    mint_policy.bump = ctx.bumps.mint_policy;
+
+    // from now on execute_trade must pass this account
+    ctx.accounts.bot_meta.mint_policy = Some(mint_policy.key());
+    Ok(())
+}
@@ -0,0 +1,185 @@
+// programs/solscope/src/init_open_orders.rs
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program::invoke_signed;
+
+use crate::{
+    errors::SolscopeError,
+    guards,
+    openbook_v2::{self, openbook_v2_program, MarketHeader},
+    state::{BotAction, BotMeta, OpenOrdersEntry, OpenOrdersRecord, PoolAllowlist, SessionKey},
+};
+
+#[derive(Accounts)]
+#[instruction(bot_id_hash: [u8; 32])]
+pub struct InitOpenOrders<'info> {
+    /// Owner, delegated operator or live session key (pays rent for every new account)
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// BotMeta PDA (derived from the stored owner, not the signer)
+    #[account(
+        mut,
+        seeds = [b"bot", bot_meta.owner.as_ref(), &bot_id_hash],
+        bump,
+        has_one = vault
+    )]
+    pub bot_meta: Account<'info, BotMeta>,
+
+    /// CHECK: Vault PDA (owner of the open-orders account)
+    #[account(
+        seeds = [b"vault", bot_meta.owner.as_ref(), &bot_id_hash],
+        bump
+    )]
+    pub vault: AccountInfo<'info>,
+
+    /// SessionKey PDA (required only when authority is a session key)
+    #[account(
+        mut,
+        seeds = [b"session", bot_meta.key().as_ref(), authority.key().as_ref()],
+        bump = session_key.bump,
+        has_one = bot_meta,
+    )]
+    pub session_key: Option<Account<'info, SessionKey>>,
+
+    /// PoolAllowlist PDA (required once the owner has created one; lists markets)
+    #[account(
+        seeds = [b"pools", bot_meta.key().as_ref()],
+        bump = pool_allowlist.bump,
+        has_one = bot_meta,
+    )]
+    pub pool_allowlist: Option<Account<'info, PoolAllowlist>>,
+
+    /// OpenOrdersRecord PDA (1 per bot, created on first use)
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + OpenOrdersRecord::LEN,
+        seeds = [b"open_orders", bot_meta.key().as_ref()],
+        bump
+    )]
+    pub open_orders_record: Account<'info, OpenOrdersRecord>,
+
+    /// CHECK: OpenBook v2 market (owner + discriminator checked in handler)
+    pub market: AccountInfo<'info>,
+
+    /// CHECK: Vault's OpenOrdersIndexer PDA (created here if missing)
+    #[account(mut)]
+    pub open_orders_indexer: AccountInfo<'info>,
+
+    /// CHECK: New OpenOrdersAccount PDA (seeds checked by OpenBook)
+    #[account(mut)]
+    pub open_orders_account: AccountInfo<'info>,
+
+    /// CHECK: OpenBook v2 program
+    #[account(address = openbook_v2_program())]
+    pub openbook_program: AccountInfo<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<InitOpenOrders>, bot_id_hash: [u8; 32]) -> Result<()> {
+    require!(
+        ctx.accounts.bot_meta.bot_id_hash == bot_id_hash,
+        SolscopeError::BotIdMismatch
+    );
+    // a new market is a new position source
+    ctx.accounts.bot_meta.require_allows(BotAction::Buy)?;
+
+    /* ================= Signer authorization ================= */
+    // rent is paid by the authority, never by the vault
+    guards::authorize_trader(
+        &ctx.accounts.bot_meta,
+        ctx.accounts.authority.key,
+        ctx.accounts.session_key.as_mut(),
+        None,
+    )?;
+
+    /* ================= Market validation ================= */
+    let program = ctx.accounts.openbook_program.key();
+    let market_key = ctx.accounts.market.key();
+    {
+        let market = MarketHeader::load(&ctx.accounts.market, &program)?;
+        require!(market.is_permissionless(), SolscopeError::PoolNotTradable);
+    }
+    guards::check_pool_allowlist(
+        &ctx.accounts.bot_meta,
+        ctx.accounts.pool_allowlist.as_ref(),
+        &market_key,
+    )?;
+
+    let record = &mut ctx.accounts.open_orders_record;
+    if record.bot_meta == Pubkey::default() {
+        record.bot_meta = ctx.accounts.bot_meta.key();
+        record.entries = Vec::new();
+        record.bump = ctx.bumps.open_orders_record;
+    }
+    require!(
+        record.open_orders_for(&market_key).is_none(),
+        SolscopeError::OpenOrdersExists
+    );
+    require!(
+        record.entries.len() < OpenOrdersRecord::MAX_ENTRIES,
+        SolscopeError::OpenOrdersRecordFull
+    );
+
+    let vault_key = ctx.accounts.vault.key();
+    let indexer_key = ctx.accounts.open_orders_indexer.key();
+    require!(
+        openbook_v2::open_orders_indexer(&vault_key, &program) == indexer_key,
+        SolscopeError::InvalidPoolAccount
+    );
+
+    /* ================= PDA signer ================= */
+    let owner = ctx.accounts.bot_meta.owner;
+    let vault_seeds: &[&[u8]] = &[
+        b"vault",
+        owner.as_ref(),
+        &bot_id_hash,
+        &[ctx.bumps.vault],
+    ];
+    let signer_seeds = &[vault_seeds];
+
+    let payer_key = ctx.accounts.authority.key();
+    let infos = [
+        ctx.accounts.authority.to_account_info(),
+        ctx.accounts.vault.to_account_info(),
+        ctx.accounts.open_orders_indexer.to_account_info(),
+        ctx.accounts.open_orders_account.to_account_info(),
+        ctx.accounts.market.to_account_info(),
+        ctx.accounts.openbook_program.to_account_info(),
+        ctx.accounts.system_program.to_account_info(),
+    ];
+
+    /* ================= OpenBook CPIs ================= */
+    // the indexer numbers the vault's open-orders accounts across all markets
+    if ctx.accounts.open_orders_indexer.data_is_empty() {
+        let ix = openbook_v2::create_open_orders_indexer_ix(
+            &program,
+            &payer_key,
+            &vault_key,
+            &indexer_key,
+        );
+        invoke_signed(&ix, &infos, signer_seeds)?;
+    }
+
+    let open_orders_key = ctx.accounts.open_orders_account.key();
+    let ix = openbook_v2::create_open_orders_account_ix(
+        &program,
+        &payer_key,
+        &vault_key,
+        &indexer_key,
+        &open_orders_key,
+        &market_key,
+    );
+    invoke_signed(&ix, &infos, signer_seeds)?;
+
+    ctx.accounts.open_orders_record.entries.push(OpenOrdersEntry {
+        market: market_key,
+        open_orders: open_orders_key,
+    });
+
+    // from now on close_bot must pass the record (and it must be empty)
+    ctx.accounts.bot_meta.open_orders_record = Some(ctx.accounts.open_orders_record.key());
+    Ok(())
+}
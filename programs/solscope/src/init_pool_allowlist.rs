@@ -0,0 +1,45 @@
+// programs/solscope/src/init_pool_allowlist.rs
+
+use anchor_lang::prelude::*;
+
+use crate::state::{BotMeta, PoolAllowlist};
+
+#[derive(Accounts)]
+#[instruction(bot_id_hash: [u8; 32])]
+pub struct InitPoolAllowlist<'info> {
+    /// Bot owner (pays for the PoolAllowlist PDA)
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"bot", owner.key().as_ref(), &bot_id_hash],
+        bump,
+        has_one = owner,
+    )]
+    pub bot_meta: Account<'info, BotMeta>,
+
+    /// PoolAllowlist PDA (1 per bot)
+    #[account(
+        init,
+        payer = owner,
+        space = 8 + PoolAllowlist::LEN,
+        seeds = [b"pools", bot_meta.key().as_ref()],
+        bump
+    )]
+    pub pool_allowlist: Account<'info, PoolAllowlist>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<InitPoolAllowlist>, _bot_id_hash: [u8; 32]) -> Result<()> {
+    let pool_allowlist = &mut ctx.accounts.pool_allowlist;
+
+    pool_allowlist.bot_meta = ctx.accounts.bot_meta.key();
+    pool_allowlist.amms = Vec::new();
+    pool_allowlist.bump = ctx.bumps.pool_allowlist;
+
+    // from now on execute_trade must pass this account
+    ctx.accounts.bot_meta.pool_allowlist = Some(pool_allowlist.key());
+    Ok(())
+}
@@ -1,23 +1,102 @@
 // programs/solscope/src/lib.rs
 
+// every instruction module exports a `handler`; the globs below exist for Anchor's codegen
+#![allow(ambiguous_glob_reexports)]
+
 use anchor_lang::prelude::*;
 
 pub mod register_bot;
+pub mod close_bot;
+pub mod migrate_bot_meta;
 pub mod assert_vault;
 pub mod fund_vault;
 pub mod withdraw;
+pub mod fund_vault_token;
+pub mod withdraw_token;
+pub mod emergency_withdraw;
+pub mod emergency_withdraw_token;
 pub mod set_paused;
+pub mod set_status;
+pub mod set_pause_flags;
+pub mod set_recovery;
+pub mod set_operator;
+pub mod revoke_operator;
+pub mod create_session_key;
+pub mod revoke_session_key;
+pub mod set_risk_config;
+pub mod init_mint_policy;
+pub mod update_mint_policy;
+pub mod close_mint_policy;
+pub mod init_pool_allowlist;
+pub mod update_pool_allowlist;
+pub mod close_pool_allowlist;
+pub mod set_oracle_config;
 pub mod execute_trade;
+pub mod execute_token_swap;
+pub mod execute_route;
+pub mod execute_clmm_trade;
+pub mod execute_pump_trade;
+pub mod execute_jupiter_route;
+pub mod init_open_orders;
+pub mod place_limit_order;
+pub mod cancel_limit_order;
+pub mod settle_funds;
+pub mod close_open_orders;
+pub mod dex;
+pub mod guards;
+pub mod raydium;
+pub mod raydium_cpmm;
+pub mod raydium_clmm;
+pub mod orca_whirlpool;
+pub mod meteora_dlmm;
+pub mod pump_fun;
+pub mod openbook_v2;
+pub mod pyth;
 pub mod state;
 pub mod errors;
+pub mod events;
+#[cfg(test)]
+pub(crate) mod test_utils;
 
 // Re-exports (instruction contexts only)
 pub use register_bot::*;
+pub use close_bot::*;
+pub use migrate_bot_meta::*;
 pub use assert_vault::*;
 pub use fund_vault::*;
 pub use withdraw::*;
+pub use fund_vault_token::*;
+pub use withdraw_token::*;
+pub use emergency_withdraw::*;
+pub use emergency_withdraw_token::*;
 pub use set_paused::*;
+pub use set_status::*;
+pub use set_pause_flags::*;
+pub use set_recovery::*;
+pub use set_operator::*;
+pub use revoke_operator::*;
+pub use create_session_key::*;
+pub use revoke_session_key::*;
+pub use set_risk_config::*;
+pub use init_mint_policy::*;
+pub use update_mint_policy::*;
+pub use close_mint_policy::*;
+pub use init_pool_allowlist::*;
+pub use update_pool_allowlist::*;
+pub use close_pool_allowlist::*;
+pub use set_oracle_config::*;
 pub use execute_trade::*;
+pub use execute_token_swap::*;
+pub use execute_route::*;
+pub use execute_pump_trade::*;
+pub use execute_jupiter_route::*;
+pub use init_open_orders::*;
+pub use place_limit_order::*;
+pub use cancel_limit_order::*;
+pub use settle_funds::*;
+pub use close_open_orders::*;
+
+use state::{BotStatus, MintPolicyMode};
 
 declare_id!("pxrgZ1DR257Ahz7fBxUFUmE6w6kq9nktz6h7eFHTrZP");
 
@@ -35,6 +114,21 @@ pub mod solscope {
         register_bot::handler(ctx, bot_id_hash)
     }
 
+    pub fn close_bot<'info>(
+        ctx: Context<'_, '_, 'info, 'info, CloseBot<'info>>,
+        bot_id_hash: [u8; 32],
+    ) -> Result<()> {
+        close_bot::handler(ctx, bot_id_hash)
+    }
+
+    /// Resize a BotMeta created by an older program version to the current layout
+    pub fn migrate_bot_meta(
+        ctx: Context<MigrateBotMeta>,
+        bot_id_hash: [u8; 32],
+    ) -> Result<()> {
+        migrate_bot_meta::handler(ctx, bot_id_hash)
+    }
+
     /* ======================================================
      * Vault Assertions (HARD SAFETY GATE)
      * ====================================================== */
@@ -56,6 +150,14 @@ pub mod solscope {
         fund_vault::handler(ctx, bot_id_hash, amount)
     }
 
+    pub fn fund_vault_token(
+        ctx: Context<FundVaultToken>,
+        bot_id_hash: [u8; 32],
+        amount: u64,
+    ) -> Result<()> {
+        fund_vault_token::handler(ctx, bot_id_hash, amount)
+    }
+
     /* ======================================================
      * Withdrawals
      * ====================================================== */
@@ -67,6 +169,21 @@ pub mod solscope {
         withdraw::handler(ctx, bot_id_hash, amount)
     }
 
+    pub fn withdraw_all(
+        ctx: Context<Withdraw>,
+        bot_id_hash: [u8; 32],
+    ) -> Result<()> {
+        withdraw::handler_all(ctx, bot_id_hash)
+    }
+
+    pub fn withdraw_token(
+        ctx: Context<WithdrawToken>,
+        bot_id_hash: [u8; 32],
+        amount: u64,
+    ) -> Result<()> {
+        withdraw_token::handler(ctx, bot_id_hash, amount)
+    }
+
     /* ======================================================
      * Emergency Controls
      * ====================================================== */
@@ -78,16 +195,309 @@ pub mod solscope {
         set_paused::handler(ctx, bot_id_hash, paused)
     }
 
+    pub fn set_pause_flags(
+        ctx: Context<SetPauseFlags>,
+        bot_id_hash: [u8; 32],
+        pause_flags: u8,
+    ) -> Result<()> {
+        set_pause_flags::handler(ctx, bot_id_hash, pause_flags)
+    }
+
     /* ======================================================
-     * Trade Execution (Raydium CPI next)
+     * Lifecycle
      * ====================================================== */
-    pub fn execute_trade(
-        ctx: Context<ExecuteTrade>,
+    pub fn set_status(
+        ctx: Context<SetStatus>,
+        bot_id_hash: [u8; 32],
+        status: BotStatus,
+    ) -> Result<()> {
+        set_status::handler(ctx, bot_id_hash, status)
+    }
+
+    pub fn set_recovery(
+        ctx: Context<SetRecovery>,
+        bot_id_hash: [u8; 32],
+        recovery: Option<Pubkey>,
+    ) -> Result<()> {
+        set_recovery::handler(ctx, bot_id_hash, recovery)
+    }
+
+    pub fn emergency_withdraw(
+        ctx: Context<EmergencyWithdraw>,
+        bot_id_hash: [u8; 32],
+        amount: u64,
+    ) -> Result<()> {
+        emergency_withdraw::handler(ctx, bot_id_hash, amount)
+    }
+
+    pub fn emergency_withdraw_token(
+        ctx: Context<EmergencyWithdrawToken>,
+        bot_id_hash: [u8; 32],
+        amount: u64,
+    ) -> Result<()> {
+        emergency_withdraw_token::handler(ctx, bot_id_hash, amount)
+    }
+
+    /* ======================================================
+     * Operator Delegation (trade-only hot key)
+     * ====================================================== */
+    pub fn set_operator(
+        ctx: Context<SetOperator>,
+        bot_id_hash: [u8; 32],
+        operator: Pubkey,
+    ) -> Result<()> {
+        set_operator::handler(ctx, bot_id_hash, operator)
+    }
+
+    pub fn revoke_operator(
+        ctx: Context<RevokeOperator>,
+        bot_id_hash: [u8; 32],
+    ) -> Result<()> {
+        revoke_operator::handler(ctx, bot_id_hash)
+    }
+
+    /* ======================================================
+     * Session Keys (expiring, budgeted trade signers)
+     * ====================================================== */
+    pub fn create_session_key(
+        ctx: Context<CreateSessionKey>,
+        bot_id_hash: [u8; 32],
+        session_pubkey: Pubkey,
+        expires_at: i64,
+        max_lamports_per_trade: u64,
+        budget: u64,
+    ) -> Result<()> {
+        create_session_key::handler(
+            ctx,
+            bot_id_hash,
+            session_pubkey,
+            expires_at,
+            max_lamports_per_trade,
+            budget,
+        )
+    }
+
+    pub fn revoke_session_key(
+        ctx: Context<RevokeSessionKey>,
+        bot_id_hash: [u8; 32],
+    ) -> Result<()> {
+        revoke_session_key::handler(ctx, bot_id_hash)
+    }
+
+    /* ======================================================
+     * Risk Limits
+     * ====================================================== */
+    pub fn set_risk_config(
+        ctx: Context<SetRiskConfig>,
+        bot_id_hash: [u8; 32],
+        max_buy_lamports: u64,
+        max_sell_amount: u64,
+        sell_limit_mint: Option<Pubkey>,
+        max_daily_notional: u64,
+        max_trades_per_day: u32,
+    ) -> Result<()> {
+        set_risk_config::handler(
+            ctx,
+            bot_id_hash,
+            max_buy_lamports,
+            max_sell_amount,
+            sell_limit_mint,
+            max_daily_notional,
+            max_trades_per_day,
+        )
+    }
+
+    /* ======================================================
+     * Mint Policy (allowlist / denylist)
+     * ====================================================== */
+    pub fn init_mint_policy(
+        ctx: Context<InitMintPolicy>,
+        bot_id_hash: [u8; 32],
+        mode: MintPolicyMode,
+    ) -> Result<()> {
+        init_mint_policy::handler(ctx, bot_id_hash, mode)
+    }
+
+    pub fn update_mint_policy(
+        ctx: Context<UpdateMintPolicy>,
+        bot_id_hash: [u8; 32],
+        mode: MintPolicyMode,
+        add: Vec<Pubkey>,
+        remove: Vec<Pubkey>,
+    ) -> Result<()> {
+        update_mint_policy::handler(ctx, bot_id_hash, mode, add, remove)
+    }
+
+    pub fn close_mint_policy(ctx: Context<CloseMintPolicy>, bot_id_hash: [u8; 32]) -> Result<()> {
+        close_mint_policy::handler(ctx, bot_id_hash)
+    }
+
+    /* ======================================================
+     * Pool Allowlist
+     * ====================================================== */
+    pub fn init_pool_allowlist(
+        ctx: Context<InitPoolAllowlist>,
+        bot_id_hash: [u8; 32],
+    ) -> Result<()> {
+        init_pool_allowlist::handler(ctx, bot_id_hash)
+    }
+
+    pub fn update_pool_allowlist(
+        ctx: Context<UpdatePoolAllowlist>,
+        bot_id_hash: [u8; 32],
+        add: Vec<Pubkey>,
+        remove: Vec<Pubkey>,
+    ) -> Result<()> {
+        update_pool_allowlist::handler(ctx, bot_id_hash, add, remove)
+    }
+
+    pub fn close_pool_allowlist(
+        ctx: Context<ClosePoolAllowlist>,
+        bot_id_hash: [u8; 32],
+    ) -> Result<()> {
+        close_pool_allowlist::handler(ctx, bot_id_hash)
+    }
+
+    /* ======================================================
+     * Oracle Price Band
+     * ====================================================== */
+    pub fn set_oracle_config(
+        ctx: Context<SetOracleConfig>,
+        bot_id_hash: [u8; 32],
+        max_deviation_bps: u16,
+        max_staleness_secs: u32,
+    ) -> Result<()> {
+        set_oracle_config::handler(ctx, bot_id_hash, max_deviation_bps, max_staleness_secs)
+    }
+
+    /* ======================================================
+     * Trade Execution (venue picked by `dex`, see dex.rs)
+     * ====================================================== */
+    pub fn execute_trade<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ExecuteTrade<'info>>,
+        bot_id_hash: [u8; 32],
+        dex: u8,
+        side: u8,
+        amount_in: u64,
+        min_out: u64,
+    ) -> Result<()> {
+        execute_trade::handler(ctx, bot_id_hash, dex, side, amount_in, min_out)
+    }
+
+    pub fn execute_trade_exact_out<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ExecuteTrade<'info>>,
+        bot_id_hash: [u8; 32],
+        dex: u8,
+        side: u8,
+        max_amount_in: u64,
+        amount_out: u64,
+    ) -> Result<()> {
+        execute_trade::handler_exact_out(ctx, bot_id_hash, dex, side, max_amount_in, amount_out)
+    }
+
+    pub fn execute_token_swap<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ExecuteTokenSwap<'info>>,
+        bot_id_hash: [u8; 32],
+        dex: u8,
+        amount_in: u64,
+        min_out: u64,
+    ) -> Result<()> {
+        execute_token_swap::handler(ctx, bot_id_hash, dex, amount_in, min_out)
+    }
+
+    pub fn execute_route<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ExecuteRoute<'info>>,
+        bot_id_hash: [u8; 32],
+        hops: Vec<u8>,
+        amount_in: u64,
+        min_out: u64,
+    ) -> Result<()> {
+        execute_route::handler(ctx, bot_id_hash, hops, amount_in, min_out)
+    }
+
+    pub fn execute_clmm_trade<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ExecuteTrade<'info>>,
         bot_id_hash: [u8; 32],
         side: u8,
         amount_in: u64,
         min_out: u64,
+        sqrt_price_limit_x64: u128,
+    ) -> Result<()> {
+        execute_clmm_trade::handler(ctx, bot_id_hash, side, amount_in, min_out, sqrt_price_limit_x64)
+    }
+
+    pub fn execute_pump_trade(
+        ctx: Context<ExecutePumpTrade>,
+        bot_id_hash: [u8; 32],
+        side: u8,
+        token_amount: u64,
+        sol_amount: u64,
+    ) -> Result<()> {
+        execute_pump_trade::handler(ctx, bot_id_hash, side, token_amount, sol_amount)
+    }
+
+    pub fn execute_jupiter_route<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ExecuteJupiterRoute<'info>>,
+        bot_id_hash: [u8; 32],
+        route_data: Vec<u8>,
+        amount_in: u64,
+        min_out: u64,
+    ) -> Result<()> {
+        execute_jupiter_route::handler(ctx, bot_id_hash, route_data, amount_in, min_out)
+    }
+
+    /* ======================================================
+     * OpenBook v2 Limit Orders (vault-owned open-orders accounts)
+     * ====================================================== */
+    pub fn init_open_orders(
+        ctx: Context<InitOpenOrders>,
+        bot_id_hash: [u8; 32],
+    ) -> Result<()> {
+        init_open_orders::handler(ctx, bot_id_hash)
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn place_limit_order<'info>(
+        ctx: Context<'_, '_, 'info, 'info, PlaceLimitOrder<'info>>,
+        bot_id_hash: [u8; 32],
+        side: u8,
+        price_lots: i64,
+        max_base_lots: i64,
+        max_quote_lots_including_fees: i64,
+        client_order_id: u64,
+        expiry_timestamp: u64,
+    ) -> Result<()> {
+        place_limit_order::handler(
+            ctx,
+            bot_id_hash,
+            side,
+            price_lots,
+            max_base_lots,
+            max_quote_lots_including_fees,
+            client_order_id,
+            expiry_timestamp,
+        )
+    }
+
+    pub fn cancel_limit_order(
+        ctx: Context<CancelLimitOrder>,
+        bot_id_hash: [u8; 32],
+        client_order_id: u64,
+    ) -> Result<()> {
+        cancel_limit_order::handler(ctx, bot_id_hash, client_order_id)
+    }
+
+    pub fn settle_funds(
+        ctx: Context<SettleFunds>,
+        bot_id_hash: [u8; 32],
+    ) -> Result<()> {
+        settle_funds::handler(ctx, bot_id_hash)
+    }
+
+    pub fn close_open_orders(
+        ctx: Context<CloseOpenOrders>,
+        bot_id_hash: [u8; 32],
     ) -> Result<()> {
-        execute_trade::handler(ctx, bot_id_hash, side, amount_in, min_out)
+        close_open_orders::handler(ctx, bot_id_hash)
     }
 }
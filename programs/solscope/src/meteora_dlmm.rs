@@ -0,0 +1,496 @@
+// programs/solscope/src/meteora_dlmm.rs
+
+use std::cell::Ref;
+use std::collections::BTreeSet;
+use std::str::FromStr;
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::{AccountMeta, Instruction};
+use anchor_spl::token;
+use bytemuck::{Pod, Zeroable};
+
+use crate::dex::{token_2022_program_id, DexAdapter, SwapMode};
+use crate::errors::SolscopeError;
+
+pub fn meteora_dlmm_program() -> Pubkey {
+    Pubkey::from_str("LBUZKhRxPF3XUpBCjp4YzTKgLccjZhTSDM9YuVaPwxo").unwrap()
+}
+
+/// Anchor discriminators (sha256("global:<name>") / sha256("account:<name>"))
+const SWAP_DISCRIMINATOR: [u8; 8] = [248, 198, 158, 145, 225, 117, 135, 200];
+const SWAP_EXACT_OUT_DISCRIMINATOR: [u8; 8] = [250, 73, 101, 33, 38, 207, 75, 184];
+const LB_PAIR_DISCRIMINATOR: [u8; 8] = [33, 11, 49, 98, 181, 101, 177, 13];
+const BIN_ARRAY_DISCRIMINATOR: [u8; 8] = [92, 142, 92, 220, 5, 148, 70, 181];
+const BITMAP_EXTENSION_DISCRIMINATOR: [u8; 8] = [80, 111, 124, 113, 55, 237, 18, 5];
+
+fn dlmm_swap_data(discriminator: [u8; 8], amount_a: u64, amount_b: u64) -> Vec<u8> {
+    // swap(amount_in, min_amount_out)
+    // swap_exact_out(max_in_amount, out_amount)
+    let mut data = Vec::with_capacity(24);
+    data.extend_from_slice(&discriminator);
+    data.extend_from_slice(&amount_a.to_le_bytes());
+    data.extend_from_slice(&amount_b.to_le_bytes());
+    data
+}
+
+/* ======================================================
+ * LbPair header (leading 576 bytes after the discriminator,
+ * through `oracle`; fee parameters and rewards kept opaque)
+ * ====================================================== */
+pub const LB_PAIR_HEADER_LEN: usize = 576;
+
+/// LbPair.status value for an enabled pair
+pub const LB_PAIR_STATUS_ENABLED: u8 = 0;
+
+#[repr(C, packed)]
+#[derive(Clone, Copy, Pod, Zeroable)]
+pub struct LbPairHeader {
+    pub parameters: [u8; 32],
+    pub v_parameters: [u8; 32],
+    pub bump_seed: u8,
+    pub bin_step_seed: [u8; 2],
+    pub pair_type: u8,
+    pub active_id: i32,
+    pub bin_step: u16,
+    pub status: u8,
+    pub require_base_factor_seed: u8,
+    pub base_factor_seed: [u8; 2],
+    pub activation_type: u8,
+    pub padding0: u8,
+    pub token_x_mint: Pubkey,
+    pub token_y_mint: Pubkey,
+    pub reserve_x: Pubkey,
+    pub reserve_y: Pubkey,
+    pub protocol_fee_amount_x: u64,
+    pub protocol_fee_amount_y: u64,
+    pub padding1: [u8; 32],
+    pub reward_infos: [u8; 288],
+    pub oracle: Pubkey,
+}
+
+const _: () = assert!(std::mem::size_of::<LbPairHeader>() == LB_PAIR_HEADER_LEN);
+
+impl LbPairHeader {
+    /// Zero-copy view of a pair account that must be owned by the DLMM program
+    pub fn load<'a>(lb_pair: &'a AccountInfo, program: &Pubkey) -> Result<Ref<'a, Self>> {
+        require!(lb_pair.owner == program, SolscopeError::InvalidPoolAccount);
+
+        let data = lb_pair.try_borrow_data()?;
+        require!(
+            data.len() >= 8 + LB_PAIR_HEADER_LEN && data[..8] == LB_PAIR_DISCRIMINATOR,
+            SolscopeError::InvalidPoolAccount
+        );
+        Ok(Ref::map(data, |data| {
+            bytemuck::from_bytes(&data[8..8 + LB_PAIR_HEADER_LEN])
+        }))
+    }
+}
+
+/// BinArray: disc (8) + index (8) + version/padding (8) + lb_pair (32) + bins
+const BIN_ARRAY_LB_PAIR_OFFSET: usize = 24;
+/// BinArrayBitmapExtension: disc (8) + lb_pair (32) + bitmaps
+const BITMAP_EXTENSION_LB_PAIR_OFFSET: usize = 8;
+
+fn belongs_to_pair(
+    account: &AccountInfo,
+    program: &Pubkey,
+    lb_pair: &Pubkey,
+    discriminator: &[u8; 8],
+    lb_pair_offset: usize,
+) -> Result<bool> {
+    if account.owner != program {
+        return Ok(false);
+    }
+    let data = account.try_borrow_data()?;
+    Ok(data.len() >= lb_pair_offset + 32
+        && data[..8] == discriminator[..]
+        && data[lb_pair_offset..lb_pair_offset + 32] == lb_pair.as_ref()[..])
+}
+
+/* ======================================================
+ * DLMM swap accounts (DEX_METEORA_DLMM venue layout,
+ * passed through remaining_accounts in this order).
+ * Every account after these is a bin array, so DLMM must be
+ * the last venue listed. Pass the DLMM program id as
+ * `bin_array_bitmap_extension` when the pair has none.
+ * ====================================================== */
+pub const METEORA_DLMM_ACCOUNTS_LEN: usize = 11;
+
+#[derive(Accounts)]
+pub struct MeteoraDlmmAccounts<'info> {
+    /// CHECK: Meteora DLMM program
+    #[account(address = meteora_dlmm_program())]
+    pub dlmm_program: AccountInfo<'info>,
+
+    /// CHECK: LbPair state
+    #[account(mut)]
+    pub lb_pair: AccountInfo<'info>,
+    /// CHECK: Bitmap extension (or the DLMM program id for none)
+    pub bin_array_bitmap_extension: AccountInfo<'info>,
+    /// CHECK: Pair reserve for token X
+    #[account(mut)]
+    pub reserve_x: AccountInfo<'info>,
+    /// CHECK: Pair reserve for token Y
+    #[account(mut)]
+    pub reserve_y: AccountInfo<'info>,
+    /// CHECK: Token X mint
+    pub token_x_mint: AccountInfo<'info>,
+    /// CHECK: Token Y mint
+    pub token_y_mint: AccountInfo<'info>,
+    /// CHECK: Pair oracle
+    #[account(mut)]
+    pub oracle: AccountInfo<'info>,
+    /// CHECK: Token program of token X
+    pub token_x_program: AccountInfo<'info>,
+    /// CHECK: Token program of token Y
+    pub token_y_program: AccountInfo<'info>,
+    /// CHECK: Anchor event authority PDA ("__event_authority")
+    pub event_authority: AccountInfo<'info>,
+}
+
+/// DLMM venue: fixed accounts + trailing bin arrays
+pub struct MeteoraDlmmVenue<'info> {
+    pub accounts: MeteoraDlmmAccounts<'info>,
+    pub bin_arrays: &'info [AccountInfo<'info>],
+}
+
+impl<'info> DexAdapter<'info> for MeteoraDlmmVenue<'info> {
+    /// Consumes every remaining account (bin arrays, at least one)
+    fn load(accounts: &mut &'info [AccountInfo<'info>]) -> Result<Self> {
+        require!(
+            accounts.len() > METEORA_DLMM_ACCOUNTS_LEN,
+            SolscopeError::InvalidPoolAccount
+        );
+        let fixed = MeteoraDlmmAccounts::try_accounts(
+            &crate::ID,
+            accounts,
+            &[],
+            &mut MeteoraDlmmAccountsBumps::default(),
+            &mut BTreeSet::new(),
+        )?;
+
+        let bin_arrays = *accounts;
+        *accounts = &[];
+
+        Ok(Self {
+            accounts: fixed,
+            bin_arrays,
+        })
+    }
+
+    fn program_id(&self) -> Pubkey {
+        self.accounts.dlmm_program.key()
+    }
+
+    fn pool_id(&self) -> Pubkey {
+        self.accounts.lb_pair.key()
+    }
+
+    fn validate(&mut self, input_mint: &Pubkey, output_mint: &Pubkey) -> Result<()> {
+        let accounts = &self.accounts;
+        let program = accounts.dlmm_program.key;
+        let lb_pair = accounts.lb_pair.key;
+        let pair = LbPairHeader::load(&accounts.lb_pair, program)?;
+        require!(
+            pair.status == LB_PAIR_STATUS_ENABLED,
+            SolscopeError::PoolNotTradable
+        );
+        require!(
+            pair.reserve_x == accounts.reserve_x.key()
+                && pair.reserve_y == accounts.reserve_y.key()
+                && pair.token_x_mint == accounts.token_x_mint.key()
+                && pair.token_y_mint == accounts.token_y_mint.key()
+                && pair.oracle == accounts.oracle.key(),
+            SolscopeError::InvalidPoolAccount
+        );
+
+        // DLMM infers the direction from the input mint; just require the pair matches
+        require!(
+            (pair.token_x_mint == *input_mint && pair.token_y_mint == *output_mint)
+                || (pair.token_y_mint == *input_mint && pair.token_x_mint == *output_mint),
+            SolscopeError::PoolMintMismatch
+        );
+
+        for token_program in [&accounts.token_x_program, &accounts.token_y_program] {
+            require!(
+                token_program.key() == token::ID || token_program.key() == token_2022_program_id(),
+                SolscopeError::InvalidPoolAccount
+            );
+        }
+
+        let (event_authority, _) = Pubkey::find_program_address(&[b"__event_authority"], program);
+        require!(
+            event_authority == accounts.event_authority.key(),
+            SolscopeError::InvalidPoolAccount
+        );
+
+        let extension = &accounts.bin_array_bitmap_extension;
+        require!(
+            extension.key == program
+                || belongs_to_pair(
+                    extension,
+                    program,
+                    lb_pair,
+                    &BITMAP_EXTENSION_DISCRIMINATOR,
+                    BITMAP_EXTENSION_LB_PAIR_OFFSET
+                )?,
+            SolscopeError::InvalidPoolAccount
+        );
+
+        // bin arrays must belong to this pair
+        for bin_array in self.bin_arrays {
+            require!(
+                belongs_to_pair(
+                    bin_array,
+                    program,
+                    lb_pair,
+                    &BIN_ARRAY_DISCRIMINATOR,
+                    BIN_ARRAY_LB_PAIR_OFFSET
+                )?,
+                SolscopeError::InvalidPoolAccount
+            );
+        }
+
+        Ok(())
+    }
+
+    /// DLMM swap / swap_exact_out (same layout; no host fee account)
+    fn swap_ix(
+        &self,
+        user_source: &Pubkey,
+        user_dest: &Pubkey,
+        user_owner: &Pubkey,
+        mode: SwapMode,
+        amount_in: u64,
+        min_out: u64,
+    ) -> Result<Instruction> {
+        let accounts = &self.accounts;
+        let program = accounts.dlmm_program.key;
+
+        // optional accounts are "None" when set to the program id
+        let extension = if accounts.bin_array_bitmap_extension.key == program {
+            AccountMeta::new_readonly(*program, false)
+        } else {
+            AccountMeta::new(*accounts.bin_array_bitmap_extension.key, false)
+        };
+
+        let mut metas = vec![
+            AccountMeta::new(*accounts.lb_pair.key, false),
+            extension,
+            AccountMeta::new(*accounts.reserve_x.key, false),
+            AccountMeta::new(*accounts.reserve_y.key, false),
+            AccountMeta::new(*user_source, false),
+            AccountMeta::new(*user_dest, false),
+            AccountMeta::new_readonly(*accounts.token_x_mint.key, false),
+            AccountMeta::new_readonly(*accounts.token_y_mint.key, false),
+            AccountMeta::new(*accounts.oracle.key, false),
+            AccountMeta::new_readonly(*program, false), // host_fee_in: None
+            AccountMeta::new_readonly(*user_owner, true),
+            AccountMeta::new_readonly(*accounts.token_x_program.key, false),
+            AccountMeta::new_readonly(*accounts.token_y_program.key, false),
+            AccountMeta::new_readonly(*accounts.event_authority.key, false),
+            AccountMeta::new_readonly(*program, false),
+        ];
+        metas.extend(
+            self.bin_arrays
+                .iter()
+                .map(|bin_array| AccountMeta::new(*bin_array.key, false)),
+        );
+
+        let data = match mode {
+            SwapMode::ExactIn => dlmm_swap_data(SWAP_DISCRIMINATOR, amount_in, min_out),
+            SwapMode::ExactOut => dlmm_swap_data(SWAP_EXACT_OUT_DISCRIMINATOR, amount_in, min_out),
+        };
+
+        Ok(Instruction {
+            program_id: *program,
+            accounts: metas,
+            data,
+        })
+    }
+
+    fn cpi_accounts(&self) -> Vec<AccountInfo<'info>> {
+        let mut infos = self.accounts.to_account_infos();
+        infos.extend(self.bin_arrays.iter().cloned());
+        infos
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{put, TestAccount};
+
+    struct Pair {
+        lb_pair: Pubkey,
+        x_mint: Pubkey,
+        y_mint: Pubkey,
+        reserve_x: Pubkey,
+        reserve_y: Pubkey,
+        oracle: Pubkey,
+    }
+
+    impl Pair {
+        fn new() -> Self {
+            Self {
+                lb_pair: Pubkey::new_unique(),
+                x_mint: Pubkey::new_unique(),
+                y_mint: Pubkey::new_unique(),
+                reserve_x: Pubkey::new_unique(),
+                reserve_y: Pubkey::new_unique(),
+                oracle: Pubkey::new_unique(),
+            }
+        }
+
+        /// LbPair account bytes, fields at their on-chain offsets
+        fn lb_pair_data(&self, status: u8) -> Vec<u8> {
+            let mut data = vec![0u8; 8 + LB_PAIR_HEADER_LEN];
+            put(&mut data, 0, &LB_PAIR_DISCRIMINATOR);
+            data[82] = status;
+            put(&mut data, 88, self.x_mint.as_ref());
+            put(&mut data, 120, self.y_mint.as_ref());
+            put(&mut data, 152, self.reserve_x.as_ref());
+            put(&mut data, 184, self.reserve_y.as_ref());
+            put(&mut data, 552, self.oracle.as_ref());
+            data
+        }
+
+        /// The 11 fixed accounts followed by `bin_arrays`
+        fn accounts(&self, status: u8, bin_arrays: Vec<TestAccount>) -> Vec<TestAccount> {
+            let program = meteora_dlmm_program();
+            let (event_authority, _) =
+                Pubkey::find_program_address(&[b"__event_authority"], &program);
+            let none = Pubkey::default();
+            let mut accounts = vec![
+                TestAccount::with_key(program, none, vec![]),
+                TestAccount::with_key(self.lb_pair, program, self.lb_pair_data(status)),
+                TestAccount::with_key(program, none, vec![]), // no bitmap extension
+                TestAccount::with_key(self.reserve_x, token::ID, vec![]),
+                TestAccount::with_key(self.reserve_y, token_2022_program_id(), vec![]),
+                TestAccount::with_key(self.x_mint, token::ID, vec![]),
+                TestAccount::with_key(self.y_mint, token_2022_program_id(), vec![]),
+                TestAccount::with_key(self.oracle, program, vec![]),
+                TestAccount::with_key(token::ID, none, vec![]),
+                TestAccount::with_key(token_2022_program_id(), none, vec![]),
+                TestAccount::with_key(event_authority, none, vec![]),
+            ];
+            accounts.extend(bin_arrays);
+            accounts
+        }
+    }
+
+    fn bin_array(lb_pair: &Pubkey, owner: Pubkey) -> TestAccount {
+        let mut data = vec![0u8; BIN_ARRAY_LB_PAIR_OFFSET + 32];
+        put(&mut data, 0, &BIN_ARRAY_DISCRIMINATOR);
+        put(&mut data, BIN_ARRAY_LB_PAIR_OFFSET, lb_pair.as_ref());
+        TestAccount::new(owner, data)
+    }
+
+    fn validate(accounts: &mut [TestAccount], input: &Pubkey, output: &Pubkey) -> Result<()> {
+        let infos: Vec<AccountInfo> = accounts.iter_mut().map(TestAccount::info).collect();
+        let mut remaining: &[AccountInfo] = &infos;
+        let mut venue = MeteoraDlmmVenue::load(&mut remaining)?;
+        assert!(remaining.is_empty());
+        venue.validate(input, output)
+    }
+
+    #[test]
+    fn valid_pair_swaps_in_both_directions() {
+        let pair = Pair::new();
+        let bins = vec![
+            bin_array(&pair.lb_pair, meteora_dlmm_program()),
+            bin_array(&pair.lb_pair, meteora_dlmm_program()),
+        ];
+        let mut accounts = pair.accounts(LB_PAIR_STATUS_ENABLED, bins);
+
+        validate(&mut accounts, &pair.x_mint, &pair.y_mint).unwrap();
+        validate(&mut accounts, &pair.y_mint, &pair.x_mint).unwrap();
+    }
+
+    #[test]
+    fn load_requires_at_least_one_bin_array() {
+        let pair = Pair::new();
+        let mut accounts = pair.accounts(LB_PAIR_STATUS_ENABLED, vec![]);
+        let err = validate(&mut accounts, &pair.x_mint, &pair.y_mint).unwrap_err();
+        assert_eq!(err, SolscopeError::InvalidPoolAccount.into());
+    }
+
+    #[test]
+    fn rejects_unrelated_mints_and_disabled_pairs() {
+        let pair = Pair::new();
+        let bins = vec![bin_array(&pair.lb_pair, meteora_dlmm_program())];
+
+        let mut accounts = pair.accounts(LB_PAIR_STATUS_ENABLED, bins);
+        let err = validate(&mut accounts, &pair.x_mint, &Pubkey::new_unique()).unwrap_err();
+        assert_eq!(err, SolscopeError::PoolMintMismatch.into());
+
+        accounts[1].data[82] = 1; // disabled
+        let err = validate(&mut accounts, &pair.x_mint, &pair.y_mint).unwrap_err();
+        assert_eq!(err, SolscopeError::PoolNotTradable.into());
+    }
+
+    #[test]
+    fn rejects_accounts_that_do_not_match_the_pair() {
+        let pair = Pair::new();
+        let fresh = || {
+            pair.accounts(
+                LB_PAIR_STATUS_ENABLED,
+                vec![bin_array(&pair.lb_pair, meteora_dlmm_program())],
+            )
+        };
+
+        // pair account not owned by the DLMM program
+        let mut accounts = fresh();
+        accounts[1].owner = Pubkey::new_unique();
+        let err = validate(&mut accounts, &pair.x_mint, &pair.y_mint).unwrap_err();
+        assert_eq!(err, SolscopeError::InvalidPoolAccount.into());
+
+        // swapped-in reserve / oracle / event authority / token program
+        for index in [3, 7, 10, 9] {
+            let mut accounts = fresh();
+            accounts[index].key = Pubkey::new_unique();
+            let err = validate(&mut accounts, &pair.x_mint, &pair.y_mint).unwrap_err();
+            assert_eq!(
+                err,
+                SolscopeError::InvalidPoolAccount.into(),
+                "account {index}"
+            );
+        }
+    }
+
+    #[test]
+    fn bin_arrays_and_extension_must_belong_to_the_pair() {
+        let pair = Pair::new();
+        let program = meteora_dlmm_program();
+
+        // bin array of another pair, then one owned by another program
+        for bin in [
+            bin_array(&Pubkey::new_unique(), program),
+            bin_array(&pair.lb_pair, Pubkey::new_unique()),
+        ] {
+            let bins = vec![bin_array(&pair.lb_pair, program), bin];
+            let mut accounts = pair.accounts(LB_PAIR_STATUS_ENABLED, bins);
+            let err = validate(&mut accounts, &pair.x_mint, &pair.y_mint).unwrap_err();
+            assert_eq!(err, SolscopeError::InvalidPoolAccount.into());
+        }
+
+        // bitmap extension of this pair passes, one of another pair doesn't
+        for (ext_pair, ok) in [(pair.lb_pair, true), (Pubkey::new_unique(), false)] {
+            let mut data = vec![0u8; BITMAP_EXTENSION_LB_PAIR_OFFSET + 32];
+            put(&mut data, 0, &BITMAP_EXTENSION_DISCRIMINATOR);
+            put(
+                &mut data,
+                BITMAP_EXTENSION_LB_PAIR_OFFSET,
+                ext_pair.as_ref(),
+            );
+
+            let bins = vec![bin_array(&pair.lb_pair, program)];
+            let mut accounts = pair.accounts(LB_PAIR_STATUS_ENABLED, bins);
+            accounts[2] = TestAccount::new(program, data);
+            assert_eq!(
+                validate(&mut accounts, &pair.x_mint, &pair.y_mint).is_ok(),
+                ok
+            );
+        }
+    }
+}
@@ -0,0 +1,121 @@
+// programs/solscope/src/migrate_bot_meta.rs
+
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use anchor_lang::Discriminator;
+
+use crate::errors::SolscopeError;
+use crate::state::{BotMeta, BotStatus};
+
+/// Baseline BotMeta body: owner, bot_id_hash, vault, created_at, bump, paused
+pub const BOT_META_BASELINE_LEN: usize = 32 + 32 + 32 + 8 + 1 + 1;
+
+#[derive(Accounts)]
+#[instruction(bot_id_hash: [u8; 32])]
+pub struct MigrateBotMeta<'info> {
+    /// Bot owner (pays the extra rent)
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    /// CHECK: BotMeta PDA in an older, shorter layout (can't deserialize until resized)
+    #[account(
+        mut,
+        seeds = [b"bot", owner.key().as_ref(), &bot_id_hash],
+        bump,
+        owner = crate::ID,
+    )]
+    pub bot_meta: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Grow a BotMeta created by an older program version to the current layout.
+/// Every field added since the baseline was appended and defaults to zero bytes
+/// (None / Active / 0), so the new tail is zero-filled.
+pub fn handler(ctx: Context<MigrateBotMeta>, bot_id_hash: [u8; 32]) -> Result<()> {
+    let bot_meta = ctx.accounts.bot_meta.to_account_info();
+    let old_len = bot_meta.data_len();
+    let new_len = 8 + BotMeta::LEN;
+
+    {
+        let data = bot_meta.try_borrow_data()?;
+        require!(
+            old_len >= 8 + BOT_META_BASELINE_LEN && data[..8] == BotMeta::DISCRIMINATOR,
+            SolscopeError::InvalidBotMeta
+        );
+        // owner / bot_id_hash are the first two fields in every layout
+        require!(
+            data[8..40] == ctx.accounts.owner.key().to_bytes() && data[40..72] == bot_id_hash,
+            SolscopeError::InvalidBotMeta
+        );
+    }
+
+    // already current: nothing to do
+    if old_len >= new_len {
+        return Ok(());
+    }
+
+    /* ================= Top up rent, then grow ================= */
+    let rent_due = Rent::get()?
+        .minimum_balance(new_len)
+        .saturating_sub(bot_meta.lamports());
+    if rent_due > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: ctx.accounts.owner.to_account_info(),
+                    to: bot_meta.clone(),
+                },
+            ),
+            rent_due,
+        )?;
+    }
+    bot_meta.realloc(new_len, true)?;
+
+    /* ================= Default the new fields ================= */
+    let mut data = bot_meta.try_borrow_mut_data()?;
+    let mut meta = BotMeta::try_deserialize(&mut &data[..])?;
+
+    // the baseline `paused: bool` sits where pause_flags is now; a paused
+    // baseline bot comes back Paused rather than Active
+    if old_len == 8 + BOT_META_BASELINE_LEN && meta.pause_flags != 0 {
+        meta.pause_flags = 0;
+        meta.status = BotStatus::Paused;
+    }
+    meta.try_serialize(&mut &mut data[..])?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn zero_filled_baseline_tail_deserializes_to_defaults() {
+        let owner = Pubkey::new_unique();
+        let mut data = BotMeta::DISCRIMINATOR.to_vec();
+        data.extend_from_slice(owner.as_ref()); // owner
+        data.extend_from_slice(&[7; 32]); // bot_id_hash
+        data.extend_from_slice(Pubkey::new_unique().as_ref()); // vault
+        data.extend_from_slice(&1_700_000_000i64.to_le_bytes()); // created_at
+        data.extend_from_slice(&[254, 0]); // bump, paused
+        assert_eq!(data.len(), 8 + BOT_META_BASELINE_LEN);
+
+        // baseline accounts are too short for the current layout
+        assert!(BotMeta::try_deserialize(&mut &data[..]).is_err());
+
+        data.resize(8 + BotMeta::LEN, 0);
+        let meta = BotMeta::try_deserialize(&mut &data[..]).unwrap();
+        assert_eq!(meta.owner, owner);
+        assert_eq!(meta.bump, 254);
+        assert_eq!(meta.pause_flags, 0);
+        assert_eq!(meta.status, BotStatus::Active);
+        assert!(meta.operator.is_none() && meta.recovery.is_none());
+        assert!(meta.risk_config.is_none() && meta.mint_policy.is_none());
+        assert!(meta.pool_allowlist.is_none() && meta.oracle_config.is_none());
+        assert_eq!(meta.session_keys, 0);
+        assert!(meta.open_orders_record.is_none());
+    }
+}
@@ -0,0 +1,532 @@
+// programs/solscope/src/openbook_v2.rs
+
+use std::cell::Ref;
+use std::str::FromStr;
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::{AccountMeta, Instruction};
+use anchor_spl::token;
+use bytemuck::{Pod, Zeroable};
+
+use crate::errors::SolscopeError;
+
+pub fn openbook_v2_program() -> Pubkey {
+    Pubkey::from_str("opnb2LAfJYbRMAHHvqjCwQxanZn7ReEHp1k81EohpZb").unwrap()
+}
+
+/// Anchor discriminators (sha256("global:<name>")[..8] / sha256("account:Market")[..8])
+const CREATE_OPEN_ORDERS_INDEXER_DISCRIMINATOR: [u8; 8] = [64, 64, 153, 255, 217, 71, 249, 133];
+const CREATE_OPEN_ORDERS_ACCOUNT_DISCRIMINATOR: [u8; 8] = [204, 181, 175, 222, 40, 125, 188, 71];
+const PLACE_ORDER_DISCRIMINATOR: [u8; 8] = [51, 194, 155, 175, 109, 130, 96, 106];
+const CANCEL_BY_CLIENT_ID_DISCRIMINATOR: [u8; 8] = [115, 178, 201, 8, 175, 183, 123, 119];
+const SETTLE_FUNDS_DISCRIMINATOR: [u8; 8] = [238, 64, 163, 96, 75, 171, 16, 33];
+const CLOSE_OPEN_ORDERS_ACCOUNT_DISCRIMINATOR: [u8; 8] = [176, 74, 115, 210, 54, 179, 91, 103];
+const MARKET_DISCRIMINATOR: [u8; 8] = [219, 190, 213, 55, 0, 227, 198, 154];
+
+/// PlaceOrderType::PostOnly: rest on the book, never take
+pub const ORDER_TYPE_POST_ONLY: u8 = 2;
+/// SelfTradeBehavior::DecrementTake
+pub const SELF_TRADE_DECREMENT_TAKE: u8 = 0;
+/// Max orders matched per place_order (irrelevant for post-only, required by the args)
+pub const PLACE_ORDER_MATCH_LIMIT: u8 = 10;
+
+/// Name given to every open-orders account solscope creates
+pub const OPEN_ORDERS_NAME: &str = "solscope";
+
+/* ======================================================
+ * OpenBook v2 Market header (leading 712 bytes after the
+ * discriminator, through quote_deposit_total)
+ * ====================================================== */
+pub const MARKET_HEADER_LEN: usize = 712;
+
+#[repr(C, packed)]
+#[derive(Clone, Copy, Pod, Zeroable)]
+pub struct MarketHeader {
+    pub bump: u8,
+    pub base_decimals: u8,
+    pub quote_decimals: u8,
+    pub padding1: [u8; 5],
+    pub market_authority: Pubkey,
+    pub time_expiry: i64,
+    pub collect_fee_admin: Pubkey,
+    /// zeroed = None for the optional admins / oracles
+    pub open_orders_admin: Pubkey,
+    pub consume_events_admin: Pubkey,
+    pub close_market_admin: Pubkey,
+    pub name: [u8; 16],
+    pub bids: Pubkey,
+    pub asks: Pubkey,
+    pub event_heap: Pubkey,
+    pub oracle_a: Pubkey,
+    pub oracle_b: Pubkey,
+    pub oracle_config: [u8; 88],
+    pub quote_lot_size: i64,
+    pub base_lot_size: i64,
+    pub seq_num: u64,
+    pub registration_time: i64,
+    pub maker_fee: i64,
+    pub taker_fee: i64,
+    pub fees_accrued: u128,
+    pub fees_to_referrers: u128,
+    pub referrer_rebates_accrued: u64,
+    pub fees_available: u64,
+    pub maker_volume: u128,
+    pub taker_volume_wo_oo: u128,
+    pub base_mint: Pubkey,
+    pub quote_mint: Pubkey,
+    pub market_base_vault: Pubkey,
+    pub base_deposit_total: u64,
+    pub market_quote_vault: Pubkey,
+    pub quote_deposit_total: u64,
+}
+
+const _: () = assert!(std::mem::size_of::<MarketHeader>() == MARKET_HEADER_LEN);
+
+impl MarketHeader {
+    /// Zero-copy view of a market account that must be owned by the OpenBook v2 program
+    pub fn load<'a>(market: &'a AccountInfo, program: &Pubkey) -> Result<Ref<'a, Self>> {
+        require!(market.owner == program, SolscopeError::InvalidPoolAccount);
+
+        let data = market.try_borrow_data()?;
+        require!(
+            data.len() >= 8 + MARKET_HEADER_LEN && data[..8] == MARKET_DISCRIMINATOR,
+            SolscopeError::InvalidPoolAccount
+        );
+        Ok(Ref::map(data, |data| {
+            bytemuck::from_bytes(&data[8..8 + MARKET_HEADER_LEN])
+        }))
+    }
+
+    /// Market must trade exactly `base_mint` / `quote_mint` and own these book accounts
+    pub fn check_book(
+        &self,
+        base_mint: &Pubkey,
+        quote_mint: &Pubkey,
+        bids: &Pubkey,
+        asks: &Pubkey,
+    ) -> Result<()> {
+        require!(
+            self.base_mint == *base_mint && self.quote_mint == *quote_mint,
+            SolscopeError::PoolMintMismatch
+        );
+        require!(
+            self.bids == *bids && self.asks == *asks,
+            SolscopeError::InvalidPoolAccount
+        );
+        Ok(())
+    }
+
+    /// Markets with an open-orders admin need its signature; solscope can't provide it
+    pub fn is_permissionless(&self) -> bool {
+        self.open_orders_admin == Pubkey::default()
+    }
+
+    /// time_expiry 0 never expires; otherwise OpenBook stops taking orders from then on
+    pub fn is_expired(&self, unix_timestamp: i64) -> bool {
+        let time_expiry = self.time_expiry;
+        time_expiry != 0 && unix_timestamp >= time_expiry
+    }
+
+    /// Market vault an order locks funds in: quote for bids, base for asks
+    pub fn order_vault(&self, is_bid: bool) -> Pubkey {
+        if is_bid {
+            self.market_quote_vault
+        } else {
+            self.market_base_vault
+        }
+    }
+}
+
+/// OpenBook v2 optional accounts are passed as the program id when absent
+fn optional_meta(key: &Pubkey, program: &Pubkey, writable: bool) -> AccountMeta {
+    match (*key == Pubkey::default(), writable) {
+        (true, _) => AccountMeta::new_readonly(*program, false),
+        (false, true) => AccountMeta::new(*key, false),
+        (false, false) => AccountMeta::new_readonly(*key, false),
+    }
+}
+
+/// OpenOrdersIndexer PDA of `owner`
+pub fn open_orders_indexer(owner: &Pubkey, program: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[b"OpenOrdersIndexer", owner.as_ref()], program).0
+}
+
+/* ======================================================
+ * Instruction builders (owner / payer is the vault PDA or the caller)
+ * ====================================================== */
+pub fn create_open_orders_indexer_ix(
+    program: &Pubkey,
+    payer: &Pubkey,
+    owner: &Pubkey,
+    indexer: &Pubkey,
+) -> Instruction {
+    Instruction {
+        program_id: *program,
+        accounts: vec![
+            AccountMeta::new(*payer, true),
+            AccountMeta::new_readonly(*owner, true),
+            AccountMeta::new(*indexer, false),
+            AccountMeta::new_readonly(System::id(), false),
+        ],
+        data: CREATE_OPEN_ORDERS_INDEXER_DISCRIMINATOR.to_vec(),
+    }
+}
+
+pub fn create_open_orders_account_ix(
+    program: &Pubkey,
+    payer: &Pubkey,
+    owner: &Pubkey,
+    indexer: &Pubkey,
+    open_orders: &Pubkey,
+    market: &Pubkey,
+) -> Instruction {
+    let mut data = CREATE_OPEN_ORDERS_ACCOUNT_DISCRIMINATOR.to_vec();
+    data.extend_from_slice(&(OPEN_ORDERS_NAME.len() as u32).to_le_bytes());
+    data.extend_from_slice(OPEN_ORDERS_NAME.as_bytes());
+
+    Instruction {
+        program_id: *program,
+        accounts: vec![
+            AccountMeta::new(*payer, true),
+            AccountMeta::new_readonly(*owner, true),
+            AccountMeta::new_readonly(*program, false), // delegate_account: None
+            AccountMeta::new(*indexer, false),
+            AccountMeta::new(*open_orders, false),
+            AccountMeta::new_readonly(*market, false),
+            AccountMeta::new_readonly(System::id(), false),
+        ],
+        data,
+    }
+}
+
+/// Order parameters solscope forwards to place_order (in lots)
+pub struct LimitOrder {
+    /// 0 = bid, 1 = ask (same values as SIDE_BUY / SIDE_SELL)
+    pub side: u8,
+    pub price_lots: i64,
+    pub max_base_lots: i64,
+    pub max_quote_lots_including_fees: i64,
+    pub client_order_id: u64,
+    pub expiry_timestamp: u64,
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn place_order_ix(
+    program: &Pubkey,
+    market: &Pubkey,
+    market_header: &MarketHeader,
+    owner: &Pubkey,
+    open_orders: &Pubkey,
+    user_token_account: &Pubkey,
+    market_vault: &Pubkey,
+    order: &LimitOrder,
+) -> Instruction {
+    let mut data = PLACE_ORDER_DISCRIMINATOR.to_vec();
+    data.push(order.side);
+    data.extend_from_slice(&order.price_lots.to_le_bytes());
+    data.extend_from_slice(&order.max_base_lots.to_le_bytes());
+    data.extend_from_slice(&order.max_quote_lots_including_fees.to_le_bytes());
+    data.extend_from_slice(&order.client_order_id.to_le_bytes());
+    data.push(ORDER_TYPE_POST_ONLY);
+    data.extend_from_slice(&order.expiry_timestamp.to_le_bytes());
+    data.push(SELF_TRADE_DECREMENT_TAKE);
+    data.push(PLACE_ORDER_MATCH_LIMIT);
+
+    Instruction {
+        program_id: *program,
+        accounts: vec![
+            AccountMeta::new_readonly(*owner, true),
+            AccountMeta::new(*open_orders, false),
+            AccountMeta::new_readonly(*program, false), // open_orders_admin: None
+            AccountMeta::new(*user_token_account, false),
+            AccountMeta::new(*market, false),
+            AccountMeta::new(market_header.bids, false),
+            AccountMeta::new(market_header.asks, false),
+            AccountMeta::new(market_header.event_heap, false),
+            AccountMeta::new(*market_vault, false),
+            optional_meta(&market_header.oracle_a, program, false),
+            optional_meta(&market_header.oracle_b, program, false),
+            AccountMeta::new_readonly(token::ID, false),
+        ],
+        data,
+    }
+}
+
+pub fn cancel_order_by_client_order_id_ix(
+    program: &Pubkey,
+    market: &Pubkey,
+    market_header: &MarketHeader,
+    owner: &Pubkey,
+    open_orders: &Pubkey,
+    client_order_id: u64,
+) -> Instruction {
+    let mut data = CANCEL_BY_CLIENT_ID_DISCRIMINATOR.to_vec();
+    data.extend_from_slice(&client_order_id.to_le_bytes());
+
+    Instruction {
+        program_id: *program,
+        accounts: vec![
+            AccountMeta::new_readonly(*owner, true),
+            AccountMeta::new(*open_orders, false),
+            AccountMeta::new_readonly(*market, false),
+            AccountMeta::new(market_header.bids, false),
+            AccountMeta::new(market_header.asks, false),
+        ],
+        data,
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn settle_funds_ix(
+    program: &Pubkey,
+    market: &Pubkey,
+    market_header: &MarketHeader,
+    owner: &Pubkey,
+    penalty_payer: &Pubkey,
+    open_orders: &Pubkey,
+    user_base_account: &Pubkey,
+    user_quote_account: &Pubkey,
+) -> Instruction {
+    Instruction {
+        program_id: *program,
+        accounts: vec![
+            AccountMeta::new(*owner, true),
+            AccountMeta::new(*penalty_payer, true),
+            AccountMeta::new(*open_orders, false),
+            AccountMeta::new(*market, false),
+            AccountMeta::new_readonly(market_header.market_authority, false),
+            AccountMeta::new(market_header.market_base_vault, false),
+            AccountMeta::new(market_header.market_quote_vault, false),
+            AccountMeta::new(*user_base_account, false),
+            AccountMeta::new(*user_quote_account, false),
+            AccountMeta::new_readonly(*program, false), // referrer_account: None
+            AccountMeta::new_readonly(token::ID, false),
+            AccountMeta::new_readonly(System::id(), false),
+        ],
+        data: SETTLE_FUNDS_DISCRIMINATOR.to_vec(),
+    }
+}
+
+/// Close an empty open-orders account (no resting orders, nothing left to settle)
+pub fn close_open_orders_account_ix(
+    program: &Pubkey,
+    payer: &Pubkey,
+    owner: &Pubkey,
+    indexer: &Pubkey,
+    open_orders: &Pubkey,
+    sol_destination: &Pubkey,
+) -> Instruction {
+    Instruction {
+        program_id: *program,
+        accounts: vec![
+            AccountMeta::new(*payer, true),
+            AccountMeta::new_readonly(*owner, true),
+            AccountMeta::new(*indexer, false),
+            AccountMeta::new(*open_orders, false),
+            AccountMeta::new(*sol_destination, false),
+            AccountMeta::new_readonly(System::id(), false),
+        ],
+        data: CLOSE_OPEN_ORDERS_ACCOUNT_DISCRIMINATOR.to_vec(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{put, TestAccount};
+
+    struct Market {
+        authority: Pubkey,
+        bids: Pubkey,
+        asks: Pubkey,
+        event_heap: Pubkey,
+        base_mint: Pubkey,
+        quote_mint: Pubkey,
+        base_vault: Pubkey,
+        quote_vault: Pubkey,
+    }
+
+    impl Market {
+        fn new() -> Self {
+            Self {
+                authority: Pubkey::new_unique(),
+                bids: Pubkey::new_unique(),
+                asks: Pubkey::new_unique(),
+                event_heap: Pubkey::new_unique(),
+                base_mint: Pubkey::new_unique(),
+                quote_mint: Pubkey::new_unique(),
+                base_vault: Pubkey::new_unique(),
+                quote_vault: Pubkey::new_unique(),
+            }
+        }
+
+        /// Market account bytes, fields at their on-chain offsets
+        fn market_data(&self, open_orders_admin: Option<Pubkey>, time_expiry: i64) -> Vec<u8> {
+            let mut data = vec![0u8; 8 + MARKET_HEADER_LEN];
+            put(&mut data, 0, &MARKET_DISCRIMINATOR);
+            data[9] = 9; // base_decimals
+            data[10] = 6; // quote_decimals
+            put(&mut data, 16, self.authority.as_ref());
+            put(&mut data, 48, &time_expiry.to_le_bytes());
+            if let Some(admin) = open_orders_admin {
+                put(&mut data, 88, admin.as_ref());
+            }
+            put(&mut data, 200, self.bids.as_ref());
+            put(&mut data, 232, self.asks.as_ref());
+            put(&mut data, 264, self.event_heap.as_ref());
+            put(&mut data, 448, &1i64.to_le_bytes()); // quote_lot_size
+            put(&mut data, 456, &1_000_000i64.to_le_bytes()); // base_lot_size
+            put(&mut data, 576, self.base_mint.as_ref());
+            put(&mut data, 608, self.quote_mint.as_ref());
+            put(&mut data, 640, self.base_vault.as_ref());
+            put(&mut data, 680, self.quote_vault.as_ref());
+            data
+        }
+
+        fn header(&self, open_orders_admin: Option<Pubkey>, time_expiry: i64) -> MarketHeader {
+            let data = self.market_data(open_orders_admin, time_expiry);
+            *bytemuck::from_bytes::<MarketHeader>(&data[8..])
+        }
+    }
+
+    #[test]
+    fn market_header_decodes_at_on_chain_offsets() {
+        let market = Market::new();
+        let program = openbook_v2_program();
+        let mut account = TestAccount::new(program, market.market_data(None, 0));
+        let info = account.info();
+        let header = MarketHeader::load(&info, &program).unwrap();
+
+        assert_eq!({ header.base_decimals }, 9);
+        assert_eq!({ header.quote_decimals }, 6);
+        assert_eq!({ header.market_authority }, market.authority);
+        assert_eq!({ header.bids }, market.bids);
+        assert_eq!({ header.asks }, market.asks);
+        assert_eq!({ header.event_heap }, market.event_heap);
+        assert_eq!({ header.quote_lot_size }, 1);
+        assert_eq!({ header.base_lot_size }, 1_000_000);
+        assert_eq!({ header.base_mint }, market.base_mint);
+        assert_eq!({ header.quote_mint }, market.quote_mint);
+        assert_eq!({ header.market_base_vault }, market.base_vault);
+        assert_eq!({ header.market_quote_vault }, market.quote_vault);
+    }
+
+    #[test]
+    fn market_rejects_wrong_owner_discriminator_or_length() {
+        let market = Market::new();
+        let program = openbook_v2_program();
+        let mut account = TestAccount::new(Pubkey::new_unique(), market.market_data(None, 0));
+        assert!(MarketHeader::load(&account.info(), &program).is_err());
+
+        account.owner = program;
+        account.data.truncate(8 + MARKET_HEADER_LEN - 1);
+        assert!(MarketHeader::load(&account.info(), &program).is_err());
+
+        account.data = market.market_data(None, 0);
+        account.data[0] ^= 1;
+        assert!(MarketHeader::load(&account.info(), &program).is_err());
+    }
+
+    #[test]
+    fn check_book_requires_the_market_pair_and_book() {
+        let market = Market::new();
+        let header = market.header(None, 0);
+        let (base, quote) = (market.base_mint, market.quote_mint);
+        header
+            .check_book(&base, &quote, &market.bids, &market.asks)
+            .unwrap();
+
+        // pair reversed, or another mint on either side
+        for (base, quote) in [(quote, base), (Pubkey::new_unique(), quote), (base, base)] {
+            let err = header
+                .check_book(&base, &quote, &market.bids, &market.asks)
+                .unwrap_err();
+            assert_eq!(err, SolscopeError::PoolMintMismatch.into());
+        }
+
+        // bids / asks swapped or foreign
+        for (bids, asks) in [
+            (market.asks, market.bids),
+            (Pubkey::new_unique(), market.asks),
+            (market.bids, Pubkey::new_unique()),
+        ] {
+            let err = header.check_book(&base, &quote, &bids, &asks).unwrap_err();
+            assert_eq!(err, SolscopeError::InvalidPoolAccount.into());
+        }
+    }
+
+    #[test]
+    fn order_vault_follows_the_side() {
+        let market = Market::new();
+        let header = market.header(None, 0);
+        assert_eq!(header.order_vault(true), market.quote_vault);
+        assert_eq!(header.order_vault(false), market.base_vault);
+    }
+
+    #[test]
+    fn permissioned_or_expired_markets_are_not_tradable() {
+        let market = Market::new();
+        assert!(market.header(None, 0).is_permissionless());
+        assert!(!market
+            .header(Some(Pubkey::new_unique()), 0)
+            .is_permissionless());
+
+        let header = market.header(None, 1_700_000_000);
+        assert!(!header.is_expired(1_699_999_999));
+        assert!(header.is_expired(1_700_000_000));
+        assert!(!market.header(None, 0).is_expired(i64::MAX));
+    }
+
+    #[test]
+    fn absent_oracles_are_passed_as_the_program() {
+        let market = Market::new();
+        let header = market.header(None, 0);
+        let program = openbook_v2_program();
+        let oracle = Pubkey::new_unique();
+        let order = LimitOrder {
+            side: 1,
+            price_lots: 50,
+            max_base_lots: 2,
+            max_quote_lots_including_fees: 100,
+            client_order_id: 7,
+            expiry_timestamp: 0,
+        };
+        let (owner, open_orders, user, market_key) = (
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+        );
+
+        let ix = place_order_ix(
+            &program,
+            &market_key,
+            &header,
+            &owner,
+            &open_orders,
+            &user,
+            &header.order_vault(false),
+            &order,
+        );
+        assert_eq!(ix.accounts[8].pubkey, market.base_vault);
+        assert_eq!(ix.accounts[9].pubkey, program);
+        assert_eq!(ix.accounts[10].pubkey, program);
+        assert_eq!(ix.data[8], 1);
+        assert_eq!(ix.data[8 + 1 + 4 * 8], ORDER_TYPE_POST_ONLY);
+
+        let mut with_oracle = header;
+        with_oracle.oracle_a = oracle;
+        let ix = place_order_ix(
+            &program,
+            &market_key,
+            &with_oracle,
+            &owner,
+            &open_orders,
+            &user,
+            &header.order_vault(false),
+            &order,
+        );
+        assert_eq!(ix.accounts[9].pubkey, oracle);
+        assert_eq!(ix.accounts[10].pubkey, program);
+    }
+}
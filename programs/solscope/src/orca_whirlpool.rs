@@ -0,0 +1,484 @@
+// programs/solscope/src/orca_whirlpool.rs
+
+use std::cell::Ref;
+use std::collections::BTreeSet;
+use std::str::FromStr;
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::{AccountMeta, Instruction};
+use anchor_spl::token;
+use bytemuck::{Pod, Zeroable};
+
+use crate::dex::{DexAdapter, SwapMode};
+use crate::errors::SolscopeError;
+
+pub fn orca_whirlpool_program() -> Pubkey {
+    Pubkey::from_str("whirLbMiicVdio4qvUfM5KAg6Ct8VwpYzGff3uctyCc").unwrap()
+}
+
+/// Anchor discriminators (sha256("global:swap") / "account:Whirlpool" / "account:TickArray")
+const SWAP_DISCRIMINATOR: [u8; 8] = [248, 198, 158, 145, 225, 117, 135, 200];
+const WHIRLPOOL_DISCRIMINATOR: [u8; 8] = [63, 149, 209, 12, 225, 128, 99, 9];
+const TICK_ARRAY_DISCRIMINATOR: [u8; 8] = [69, 97, 189, 190, 110, 7, 66, 187];
+
+/// Whirlpool sqrt price bounds (Q64.64), used as "no limit" in each direction
+pub const MIN_SQRT_PRICE_X64: u128 = 4_295_048_016;
+pub const MAX_SQRT_PRICE_X64: u128 = 79_226_673_515_401_279_992_447_579_055;
+
+fn whirlpool_swap_data(
+    amount: u64,
+    other_amount_threshold: u64,
+    sqrt_price_limit: u128,
+    amount_specified_is_input: bool,
+    a_to_b: bool,
+) -> Vec<u8> {
+    let mut data = Vec::with_capacity(42);
+    data.extend_from_slice(&SWAP_DISCRIMINATOR);
+    data.extend_from_slice(&amount.to_le_bytes());
+    data.extend_from_slice(&other_amount_threshold.to_le_bytes());
+    data.extend_from_slice(&sqrt_price_limit.to_le_bytes());
+    data.push(amount_specified_is_input as u8);
+    data.push(a_to_b as u8);
+    data
+}
+
+/* ======================================================
+ * Whirlpool header (leading 253 bytes after the discriminator;
+ * reward infos are not needed here)
+ * ====================================================== */
+pub const WHIRLPOOL_HEADER_LEN: usize = 253;
+
+#[repr(C, packed)]
+#[derive(Clone, Copy, Pod, Zeroable)]
+pub struct WhirlpoolHeader {
+    pub whirlpools_config: Pubkey,
+    pub whirlpool_bump: u8,
+    pub tick_spacing: u16,
+    pub tick_spacing_seed: [u8; 2],
+    pub fee_rate: u16,
+    pub protocol_fee_rate: u16,
+    pub liquidity: u128,
+    pub sqrt_price: u128,
+    pub tick_current_index: i32,
+    pub protocol_fee_owed_a: u64,
+    pub protocol_fee_owed_b: u64,
+    pub token_mint_a: Pubkey,
+    pub token_vault_a: Pubkey,
+    pub fee_growth_global_a: u128,
+    pub token_mint_b: Pubkey,
+    pub token_vault_b: Pubkey,
+    pub fee_growth_global_b: u128,
+}
+
+const _: () = assert!(std::mem::size_of::<WhirlpoolHeader>() == WHIRLPOOL_HEADER_LEN);
+
+impl WhirlpoolHeader {
+    /// Zero-copy view of a whirlpool account that must be owned by the Whirlpool program
+    pub fn load<'a>(whirlpool: &'a AccountInfo, program: &Pubkey) -> Result<Ref<'a, Self>> {
+        require!(whirlpool.owner == program, SolscopeError::InvalidPoolAccount);
+
+        let data = whirlpool.try_borrow_data()?;
+        require!(
+            data.len() >= 8 + WHIRLPOOL_HEADER_LEN && data[..8] == WHIRLPOOL_DISCRIMINATOR,
+            SolscopeError::InvalidPoolAccount
+        );
+        Ok(Ref::map(data, |data| {
+            bytemuck::from_bytes(&data[8..8 + WHIRLPOOL_HEADER_LEN])
+        }))
+    }
+}
+
+/// Fixed TickArray: disc (8) + start_tick_index (4) + 88 ticks x 113 bytes + whirlpool (32)
+pub const TICK_ARRAY_LEN: usize = 9988;
+const TICK_ARRAY_WHIRLPOOL_OFFSET: usize = TICK_ARRAY_LEN - 32;
+
+fn tick_array_belongs_to(tick_array: &AccountInfo, program: &Pubkey, whirlpool: &Pubkey) -> Result<bool> {
+    if tick_array.owner != program {
+        return Ok(false);
+    }
+    let data = tick_array.try_borrow_data()?;
+    Ok(data.len() == TICK_ARRAY_LEN
+        && data[..8] == TICK_ARRAY_DISCRIMINATOR
+        && data[TICK_ARRAY_WHIRLPOOL_OFFSET..] == whirlpool.as_ref()[..])
+}
+
+/* ======================================================
+ * Whirlpool swap accounts (DEX_ORCA_WHIRLPOOL venue layout,
+ * passed through remaining_accounts in this order).
+ * Vaults are in the pool's a/b order; a_to_b comes from the input mint.
+ * ====================================================== */
+pub const ORCA_WHIRLPOOL_ACCOUNTS_LEN: usize = 8;
+
+#[derive(Accounts)]
+pub struct OrcaWhirlpoolAccounts<'info> {
+    /// CHECK: Orca Whirlpool program
+    #[account(address = orca_whirlpool_program())]
+    pub whirlpool_program: AccountInfo<'info>,
+
+    /// CHECK: Whirlpool state
+    #[account(mut)]
+    pub whirlpool: AccountInfo<'info>,
+    /// CHECK: Pool vault for token A
+    #[account(mut)]
+    pub token_vault_a: AccountInfo<'info>,
+    /// CHECK: Pool vault for token B
+    #[account(mut)]
+    pub token_vault_b: AccountInfo<'info>,
+    /// CHECK: Tick array containing the current tick
+    #[account(mut)]
+    pub tick_array_0: AccountInfo<'info>,
+    /// CHECK: Next tick array in the swap direction
+    #[account(mut)]
+    pub tick_array_1: AccountInfo<'info>,
+    /// CHECK: Tick array after that
+    #[account(mut)]
+    pub tick_array_2: AccountInfo<'info>,
+    /// CHECK: Oracle PDA ("oracle", whirlpool)
+    #[account(mut)]
+    pub oracle: AccountInfo<'info>,
+}
+
+/// Whirlpool venue: accounts + the direction resolved by `validate`
+pub struct OrcaWhirlpoolVenue<'info> {
+    pub accounts: OrcaWhirlpoolAccounts<'info>,
+    pub a_to_b: bool,
+}
+
+impl<'info> DexAdapter<'info> for OrcaWhirlpoolVenue<'info> {
+    fn load(accounts: &mut &'info [AccountInfo<'info>]) -> Result<Self> {
+        require!(
+            accounts.len() >= ORCA_WHIRLPOOL_ACCOUNTS_LEN,
+            SolscopeError::InvalidPoolAccount
+        );
+        let accounts = OrcaWhirlpoolAccounts::try_accounts(
+            &crate::ID,
+            accounts,
+            &[],
+            &mut OrcaWhirlpoolAccountsBumps::default(),
+            &mut BTreeSet::new(),
+        )?;
+        Ok(Self {
+            accounts,
+            a_to_b: false,
+        })
+    }
+
+    fn program_id(&self) -> Pubkey {
+        self.accounts.whirlpool_program.key()
+    }
+
+    fn pool_id(&self) -> Pubkey {
+        self.accounts.whirlpool.key()
+    }
+
+    fn validate(&mut self, input_mint: &Pubkey, output_mint: &Pubkey) -> Result<()> {
+        let accounts = &self.accounts;
+        let program = accounts.whirlpool_program.key;
+        let pool = WhirlpoolHeader::load(&accounts.whirlpool, program)?;
+        require!(
+            pool.token_vault_a == accounts.token_vault_a.key()
+                && pool.token_vault_b == accounts.token_vault_b.key(),
+            SolscopeError::InvalidPoolAccount
+        );
+
+        // BUY spends wSOL, SELL spends the token: whichever side is our input swaps first
+        let a_to_b = if pool.token_mint_a == *input_mint && pool.token_mint_b == *output_mint {
+            true
+        } else if pool.token_mint_b == *input_mint && pool.token_mint_a == *output_mint {
+            false
+        } else {
+            return err!(SolscopeError::PoolMintMismatch);
+        };
+
+        let (oracle, _) =
+            Pubkey::find_program_address(&[b"oracle", accounts.whirlpool.key.as_ref()], program);
+        require!(
+            oracle == accounts.oracle.key(),
+            SolscopeError::InvalidPoolAccount
+        );
+
+        for tick_array in [
+            &accounts.tick_array_0,
+            &accounts.tick_array_1,
+            &accounts.tick_array_2,
+        ] {
+            require!(
+                tick_array_belongs_to(tick_array, program, accounts.whirlpool.key)?,
+                SolscopeError::InvalidPoolAccount
+            );
+        }
+
+        drop(pool);
+        self.a_to_b = a_to_b;
+        Ok(())
+    }
+
+    /// Whirlpool swap: user accounts go in a/b order, so source/dest follow a_to_b
+    fn swap_ix(
+        &self,
+        user_source: &Pubkey,
+        user_dest: &Pubkey,
+        user_owner: &Pubkey,
+        mode: SwapMode,
+        amount_in: u64,
+        min_out: u64,
+    ) -> Result<Instruction> {
+        let accounts = &self.accounts;
+        let (owner_a, owner_b) = if self.a_to_b {
+            (user_source, user_dest)
+        } else {
+            (user_dest, user_source)
+        };
+        let sqrt_price_limit = if self.a_to_b {
+            MIN_SQRT_PRICE_X64
+        } else {
+            MAX_SQRT_PRICE_X64
+        };
+
+        let data = match mode {
+            SwapMode::ExactIn => {
+                whirlpool_swap_data(amount_in, min_out, sqrt_price_limit, true, self.a_to_b)
+            }
+            SwapMode::ExactOut => {
+                whirlpool_swap_data(min_out, amount_in, sqrt_price_limit, false, self.a_to_b)
+            }
+        };
+
+        Ok(Instruction {
+            program_id: accounts.whirlpool_program.key(),
+            accounts: vec![
+                AccountMeta::new_readonly(token::ID, false),
+                AccountMeta::new_readonly(*user_owner, true),
+                AccountMeta::new(*accounts.whirlpool.key, false),
+                AccountMeta::new(*owner_a, false),
+                AccountMeta::new(*accounts.token_vault_a.key, false),
+                AccountMeta::new(*owner_b, false),
+                AccountMeta::new(*accounts.token_vault_b.key, false),
+                AccountMeta::new(*accounts.tick_array_0.key, false),
+                AccountMeta::new(*accounts.tick_array_1.key, false),
+                AccountMeta::new(*accounts.tick_array_2.key, false),
+                AccountMeta::new(*accounts.oracle.key, false),
+            ],
+            data,
+        })
+    }
+
+    fn cpi_accounts(&self) -> Vec<AccountInfo<'info>> {
+        self.accounts.to_account_infos()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{put, TestAccount};
+
+    struct Pool {
+        whirlpool: Pubkey,
+        mint_a: Pubkey,
+        mint_b: Pubkey,
+        vault_a: Pubkey,
+        vault_b: Pubkey,
+    }
+
+    impl Pool {
+        fn new() -> Self {
+            Self {
+                whirlpool: Pubkey::new_unique(),
+                mint_a: Pubkey::new_unique(),
+                mint_b: Pubkey::new_unique(),
+                vault_a: Pubkey::new_unique(),
+                vault_b: Pubkey::new_unique(),
+            }
+        }
+
+        /// Whirlpool account bytes, fields at their on-chain offsets
+        fn whirlpool_data(&self) -> Vec<u8> {
+            let mut data = vec![0u8; 8 + WHIRLPOOL_HEADER_LEN];
+            put(&mut data, 0, &WHIRLPOOL_DISCRIMINATOR);
+            put(&mut data, 41, &64u16.to_le_bytes()); // tick_spacing
+            put(&mut data, 45, &3_000u16.to_le_bytes()); // fee_rate
+            put(&mut data, 65, &(1u128 << 64).to_le_bytes()); // sqrt_price
+            put(&mut data, 81, &(-5i32).to_le_bytes()); // tick_current_index
+            put(&mut data, 101, self.mint_a.as_ref());
+            put(&mut data, 133, self.vault_a.as_ref());
+            put(&mut data, 181, self.mint_b.as_ref());
+            put(&mut data, 213, self.vault_b.as_ref());
+            data
+        }
+
+        fn tick_array(&self) -> TestAccount {
+            let mut data = vec![0u8; TICK_ARRAY_LEN];
+            put(&mut data, 0, &TICK_ARRAY_DISCRIMINATOR);
+            put(
+                &mut data,
+                TICK_ARRAY_WHIRLPOOL_OFFSET,
+                self.whirlpool.as_ref(),
+            );
+            TestAccount::new(orca_whirlpool_program(), data)
+        }
+
+        /// The 8 venue accounts (vaults in a/b order regardless of direction)
+        fn accounts(&self) -> Vec<TestAccount> {
+            let program = orca_whirlpool_program();
+            let (oracle, _) =
+                Pubkey::find_program_address(&[b"oracle", self.whirlpool.as_ref()], &program);
+            let none = Pubkey::default();
+            vec![
+                TestAccount::with_key(program, none, vec![]),
+                TestAccount::with_key(self.whirlpool, program, self.whirlpool_data()),
+                TestAccount::with_key(self.vault_a, none, vec![]),
+                TestAccount::with_key(self.vault_b, none, vec![]),
+                self.tick_array(),
+                self.tick_array(),
+                self.tick_array(),
+                TestAccount::with_key(oracle, program, vec![]),
+            ]
+        }
+    }
+
+    /// Validate and return the resolved direction
+    fn validate(accounts: &mut [TestAccount], input: &Pubkey, output: &Pubkey) -> Result<bool> {
+        let infos: Vec<AccountInfo> = accounts.iter_mut().map(TestAccount::info).collect();
+        let mut remaining: &[AccountInfo] = &infos;
+        let mut venue = OrcaWhirlpoolVenue::load(&mut remaining)?;
+        assert!(remaining.is_empty());
+        venue.validate(input, output)?;
+        Ok(venue.a_to_b)
+    }
+
+    #[test]
+    fn whirlpool_header_decodes_at_on_chain_offsets() {
+        let pool = Pool::new();
+        let mut account = TestAccount::with_key(
+            pool.whirlpool,
+            orca_whirlpool_program(),
+            pool.whirlpool_data(),
+        );
+        let info = account.info();
+        let state = WhirlpoolHeader::load(&info, &orca_whirlpool_program()).unwrap();
+
+        assert_eq!({ state.tick_spacing }, 64);
+        assert_eq!({ state.fee_rate }, 3_000);
+        assert_eq!({ state.sqrt_price }, 1u128 << 64);
+        assert_eq!({ state.tick_current_index }, -5);
+        assert_eq!({ state.token_mint_a }, pool.mint_a);
+        assert_eq!({ state.token_vault_a }, pool.vault_a);
+        assert_eq!({ state.token_mint_b }, pool.mint_b);
+        assert_eq!({ state.token_vault_b }, pool.vault_b);
+    }
+
+    #[test]
+    fn whirlpool_rejects_wrong_owner_or_discriminator() {
+        let pool = Pool::new();
+        let program = orca_whirlpool_program();
+        let mut account =
+            TestAccount::with_key(pool.whirlpool, Pubkey::new_unique(), pool.whirlpool_data());
+        assert!(WhirlpoolHeader::load(&account.info(), &program).is_err());
+
+        account.owner = program;
+        account.data[0] ^= 1;
+        assert!(WhirlpoolHeader::load(&account.info(), &program).is_err());
+    }
+
+    #[test]
+    fn direction_follows_the_input_mint() {
+        let pool = Pool::new();
+        assert!(validate(&mut pool.accounts(), &pool.mint_a, &pool.mint_b).unwrap());
+        assert!(!validate(&mut pool.accounts(), &pool.mint_b, &pool.mint_a).unwrap());
+    }
+
+    #[test]
+    fn swap_ix_orders_user_accounts_a_then_b() {
+        let pool = Pool::new();
+        let mut accounts = pool.accounts();
+        let infos: Vec<AccountInfo> = accounts.iter_mut().map(TestAccount::info).collect();
+        let mut remaining: &[AccountInfo] = &infos;
+        let mut venue = OrcaWhirlpoolVenue::load(&mut remaining).unwrap();
+        let (source, dest, owner) = (
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+        );
+
+        // b -> a: the user's a account is the destination, price limit is the max
+        venue.validate(&pool.mint_b, &pool.mint_a).unwrap();
+        let ix = venue
+            .swap_ix(&source, &dest, &owner, SwapMode::ExactIn, 100, 90)
+            .unwrap();
+        assert_eq!(ix.accounts[3].pubkey, dest);
+        assert_eq!(ix.accounts[4].pubkey, pool.vault_a);
+        assert_eq!(ix.accounts[5].pubkey, source);
+        assert_eq!(ix.accounts[6].pubkey, pool.vault_b);
+        assert_eq!(
+            ix.data,
+            whirlpool_swap_data(100, 90, MAX_SQRT_PRICE_X64, true, false)
+        );
+
+        venue.validate(&pool.mint_a, &pool.mint_b).unwrap();
+        let ix = venue
+            .swap_ix(&source, &dest, &owner, SwapMode::ExactOut, 100, 90)
+            .unwrap();
+        assert_eq!(ix.accounts[3].pubkey, source);
+        assert_eq!(ix.accounts[5].pubkey, dest);
+        assert_eq!(
+            ix.data,
+            whirlpool_swap_data(90, 100, MIN_SQRT_PRICE_X64, false, true)
+        );
+    }
+
+    #[test]
+    fn rejects_unrelated_mints() {
+        let pool = Pool::new();
+        let err = validate(&mut pool.accounts(), &pool.mint_a, &Pubkey::new_unique()).unwrap_err();
+        assert_eq!(err, SolscopeError::PoolMintMismatch.into());
+        let err = validate(&mut pool.accounts(), &pool.mint_a, &pool.mint_a).unwrap_err();
+        assert_eq!(err, SolscopeError::PoolMintMismatch.into());
+    }
+
+    #[test]
+    fn rejects_accounts_that_do_not_match_the_pool() {
+        let pool = Pool::new();
+
+        // vaults / oracle (tick arrays are matched by owner and data, not key)
+        for index in [2, 3, 7] {
+            let mut accounts = pool.accounts();
+            accounts[index].key = Pubkey::new_unique();
+            let err = validate(&mut accounts, &pool.mint_a, &pool.mint_b).unwrap_err();
+            assert_eq!(
+                err,
+                SolscopeError::InvalidPoolAccount.into(),
+                "account {index}"
+            );
+        }
+
+        // vaults passed in b/a order
+        let mut accounts = pool.accounts();
+        accounts.swap(2, 3);
+        let err = validate(&mut accounts, &pool.mint_a, &pool.mint_b).unwrap_err();
+        assert_eq!(err, SolscopeError::InvalidPoolAccount.into());
+
+        // whirlpool owned by another program
+        let mut accounts = pool.accounts();
+        accounts[1].owner = Pubkey::new_unique();
+        let err = validate(&mut accounts, &pool.mint_a, &pool.mint_b).unwrap_err();
+        assert_eq!(err, SolscopeError::InvalidPoolAccount.into());
+    }
+
+    #[test]
+    fn tick_arrays_must_belong_to_the_pool() {
+        let pool = Pool::new();
+        let other = Pool::new();
+
+        let mut foreign_owner = pool.tick_array();
+        foreign_owner.owner = Pubkey::new_unique();
+        let mut short = pool.tick_array();
+        short.data.truncate(TICK_ARRAY_LEN - 1);
+        for tick_array in [other.tick_array(), foreign_owner, short] {
+            let mut accounts = pool.accounts();
+            accounts[5] = tick_array;
+            let err = validate(&mut accounts, &pool.mint_a, &pool.mint_b).unwrap_err();
+            assert_eq!(err, SolscopeError::InvalidPoolAccount.into());
+        }
+    }
+}
@@ -0,0 +1,296 @@
+// programs/solscope/src/place_limit_order.rs
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program::invoke_signed;
+
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::{self, Mint, Token, TokenAccount},
+};
+use spl_token::native_mint;
+
+use crate::{
+    errors::SolscopeError,
+    execute_trade::{SIDE_BUY, SIDE_SELL},
+    guards,
+    openbook_v2::{self, openbook_v2_program, LimitOrder, MarketHeader},
+    state::{BotAction, BotMeta, MintPolicy, OpenOrdersRecord, PoolAllowlist, RiskConfig, SessionKey},
+};
+
+#[derive(Accounts)]
+#[instruction(bot_id_hash: [u8; 32])]
+pub struct PlaceLimitOrder<'info> {
+    /// Owner, delegated operator or live session key (pays for vault ATA creation)
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// BotMeta PDA (derived from the stored owner, not the signer)
+    #[account(
+        seeds = [b"bot", bot_meta.owner.as_ref(), &bot_id_hash],
+        bump,
+        has_one = vault
+    )]
+    pub bot_meta: Account<'info, BotMeta>,
+
+    /// CHECK: Vault PDA (open-orders owner, token authority)
+    #[account(
+        seeds = [b"vault", bot_meta.owner.as_ref(), &bot_id_hash],
+        bump
+    )]
+    pub vault: AccountInfo<'info>,
+
+    /// SessionKey PDA (required only when authority is a session key)
+    #[account(
+        mut,
+        seeds = [b"session", bot_meta.key().as_ref(), authority.key().as_ref()],
+        bump = session_key.bump,
+        has_one = bot_meta,
+    )]
+    pub session_key: Option<Account<'info, SessionKey>>,
+
+    /// RiskConfig PDA (required once the owner has configured limits)
+    #[account(
+        mut,
+        seeds = [b"risk", bot_meta.key().as_ref()],
+        bump = risk_config.bump,
+        has_one = bot_meta,
+    )]
+    pub risk_config: Option<Account<'info, RiskConfig>>,
+
+    /// MintPolicy PDA (required once the owner has created one)
+    #[account(
+        seeds = [b"mint_policy", bot_meta.key().as_ref()],
+        bump = mint_policy.bump,
+        has_one = bot_meta,
+    )]
+    pub mint_policy: Option<Account<'info, MintPolicy>>,
+
+    /// PoolAllowlist PDA (required once the owner has created one; lists markets)
+    #[account(
+        seeds = [b"pools", bot_meta.key().as_ref()],
+        bump = pool_allowlist.bump,
+        has_one = bot_meta,
+    )]
+    pub pool_allowlist: Option<Account<'info, PoolAllowlist>>,
+
+    /// OpenOrdersRecord PDA
+    #[account(
+        seeds = [b"open_orders", bot_meta.key().as_ref()],
+        bump = open_orders_record.bump,
+        has_one = bot_meta,
+    )]
+    pub open_orders_record: Account<'info, OpenOrdersRecord>,
+
+    /* ========== OpenBook v2 Accounts ========== */
+    /// CHECK: OpenBook v2 market (owner + discriminator checked in handler)
+    #[account(mut)]
+    pub market: AccountInfo<'info>,
+
+    /// CHECK: Vault's open-orders account recorded for `market`
+    #[account(
+        mut,
+        constraint = open_orders_record.open_orders_for(market.key)
+            == Some(open_orders_account.key()) @ SolscopeError::OpenOrdersNotFound
+    )]
+    pub open_orders_account: AccountInfo<'info>,
+
+    /// CHECK: Market bids (checked against the market)
+    #[account(mut)]
+    pub bids: AccountInfo<'info>,
+    /// CHECK: Market asks (checked against the market)
+    #[account(mut)]
+    pub asks: AccountInfo<'info>,
+    /// CHECK: Market event heap (checked against the market)
+    #[account(mut)]
+    pub event_heap: AccountInfo<'info>,
+    /// CHECK: Market quote vault on bids, base vault on asks (checked against the market)
+    #[account(mut)]
+    pub market_vault: AccountInfo<'info>,
+
+    pub base_mint: Account<'info, Mint>,
+    pub quote_mint: Account<'info, Mint>,
+
+    /// Vault ATA for the base mint (pays on asks)
+    #[account(
+        init_if_needed,
+        payer = authority,
+        associated_token::mint = base_mint,
+        associated_token::authority = vault
+    )]
+    pub vault_base_ata: Account<'info, TokenAccount>,
+
+    /// Vault ATA for the quote mint (pays on bids)
+    #[account(
+        init_if_needed,
+        payer = authority,
+        associated_token::mint = quote_mint,
+        associated_token::authority = vault
+    )]
+    pub vault_quote_ata: Account<'info, TokenAccount>,
+
+    /// CHECK: OpenBook v2 program
+    #[account(address = openbook_v2_program())]
+    pub openbook_program: AccountInfo<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+    // remaining_accounts: the market's oracle_a / oracle_b, when set
+}
+
+/// Rest a post-only order on the book. `side` uses SIDE_BUY (bid) / SIDE_SELL (ask).
+#[allow(clippy::too_many_arguments)]
+pub fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, PlaceLimitOrder<'info>>,
+    bot_id_hash: [u8; 32],
+    side: u8,
+    price_lots: i64,
+    max_base_lots: i64,
+    max_quote_lots_including_fees: i64,
+    client_order_id: u64,
+    expiry_timestamp: u64,
+) -> Result<()> {
+    require!(
+        price_lots > 0 && max_base_lots > 0 && max_quote_lots_including_fees > 0,
+        SolscopeError::InvalidAmount
+    );
+    require!(
+        side == SIDE_BUY || side == SIDE_SELL,
+        SolscopeError::Unauthorized
+    );
+    let is_bid = side == SIDE_BUY;
+
+    require!(
+        ctx.accounts.bot_meta.bot_id_hash == bot_id_hash,
+        SolscopeError::BotIdMismatch
+    );
+    // asks stay open while liquidating / new positions are paused
+    let action = if is_bid { BotAction::Buy } else { BotAction::Sell };
+    ctx.accounts.bot_meta.require_allows(action)?;
+
+    // a resting order fills later, outside any oracle check this instruction could run
+    guards::require_no_oracle(&ctx.accounts.bot_meta)?;
+
+    /* ================= Market validation ================= */
+    let program = ctx.accounts.openbook_program.key();
+    let market_key = ctx.accounts.market.key();
+    let base_mint = ctx.accounts.base_mint.key();
+    let quote_mint = ctx.accounts.quote_mint.key();
+
+    let market = *MarketHeader::load(&ctx.accounts.market, &program)?;
+    market.check_book(&base_mint, &quote_mint, ctx.accounts.bids.key, ctx.accounts.asks.key)?;
+    require!(
+        market.event_heap == ctx.accounts.event_heap.key(),
+        SolscopeError::InvalidPoolAccount
+    );
+    require!(
+        market.is_permissionless() && !market.is_expired(Clock::get()?.unix_timestamp),
+        SolscopeError::PoolNotTradable
+    );
+
+    // bids lock quote, asks lock base; the order can't take more than its lot caps
+    let (paying_info, paying_mint, amount_in) = if is_bid {
+        let max_quote = (max_quote_lots_including_fees as u64)
+            .checked_mul(market.quote_lot_size as u64)
+            .ok_or(SolscopeError::InvalidAmount)?;
+        (
+            ctx.accounts.vault_quote_ata.to_account_info(),
+            quote_mint,
+            max_quote,
+        )
+    } else {
+        let max_base = (max_base_lots as u64)
+            .checked_mul(market.base_lot_size as u64)
+            .ok_or(SolscopeError::InvalidAmount)?;
+        (
+            ctx.accounts.vault_base_ata.to_account_info(),
+            base_mint,
+            max_base,
+        )
+    };
+    let market_vault = market.order_vault(is_bid);
+    require!(
+        market_vault == ctx.accounts.market_vault.key(),
+        SolscopeError::InvalidPoolAccount
+    );
+    let sol_in = paying_mint == native_mint::id();
+
+    /* ================= Signer authorization ================= */
+    guards::authorize_trader(
+        &ctx.accounts.bot_meta,
+        ctx.accounts.authority.key,
+        ctx.accounts.session_key.as_mut(),
+        sol_in.then_some(amount_in),
+    )?;
+
+    /* ================= Risk limits (pre-trade) ================= */
+    if let Some(risk_config) =
+        guards::load_risk_config(&ctx.accounts.bot_meta, ctx.accounts.risk_config.as_mut())?
+    {
+        risk_config.check_trade(sol_in, &paying_mint, amount_in)?;
+    }
+
+    /* ================= Mint policy + market allowlist ================= */
+    guards::check_mint_policy(
+        &ctx.accounts.bot_meta,
+        ctx.accounts.mint_policy.as_ref(),
+        &[base_mint, quote_mint],
+    )?;
+    guards::check_pool_allowlist(
+        &ctx.accounts.bot_meta,
+        ctx.accounts.pool_allowlist.as_ref(),
+        &market_key,
+    )?;
+
+    /* ================= PDA signer ================= */
+    let owner = ctx.accounts.bot_meta.owner;
+    let vault_seeds: &[&[u8]] = &[
+        b"vault",
+        owner.as_ref(),
+        &bot_id_hash,
+        &[ctx.bumps.vault],
+    ];
+    let signer_seeds = &[vault_seeds];
+
+    /* ================= Pre-order balance snapshot ================= */
+    let before_paying = token::accessor::amount(&paying_info)?;
+
+    /* ================= OpenBook place_order ================= */
+    let order = LimitOrder {
+        side,
+        price_lots,
+        max_base_lots,
+        max_quote_lots_including_fees,
+        client_order_id,
+        expiry_timestamp,
+    };
+    let ix = openbook_v2::place_order_ix(
+        &program,
+        &market_key,
+        &market,
+        ctx.accounts.vault.key,
+        ctx.accounts.open_orders_account.key,
+        paying_info.key,
+        &market_vault,
+        &order,
+    );
+
+    let mut infos = ctx.accounts.to_account_infos();
+    infos.extend_from_slice(ctx.remaining_accounts);
+
+    invoke_signed(&ix, &infos, signer_seeds)?;
+
+    /* ================= Post-order delta check ================= */
+    let locked = before_paying.saturating_sub(token::accessor::amount(&paying_info)?);
+    require!(locked <= amount_in, SolscopeError::SlippageExceeded);
+
+    /* ================= Risk limits (post-trade accounting) ================= */
+    // a resting order only locks funds; proceeds come back through settle_funds
+    let notional = if sol_in { locked } else { 0 };
+
+    if let Some(risk_config) = ctx.accounts.risk_config.as_mut() {
+        risk_config.record_trade(notional)?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,348 @@
+// programs/solscope/src/pump_fun.rs
+
+use std::cell::Ref;
+use std::str::FromStr;
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::{AccountMeta, Instruction};
+use anchor_spl::associated_token::get_associated_token_address;
+use anchor_spl::token;
+use bytemuck::{Pod, Zeroable};
+
+use crate::errors::SolscopeError;
+
+pub fn pump_fun_program() -> Pubkey {
+    Pubkey::from_str("6EF8rrecthR5Dkzon8Nwu78hRvfCKubJ14M5uBEwF6P").unwrap()
+}
+
+/// Anchor discriminators (sha256("global:<name>") / sha256("account:BondingCurve"))
+const BUY_DISCRIMINATOR: [u8; 8] = [102, 6, 61, 18, 1, 218, 235, 234];
+const SELL_DISCRIMINATOR: [u8; 8] = [51, 230, 133, 164, 1, 127, 131, 173];
+const BONDING_CURVE_DISCRIMINATOR: [u8; 8] = [23, 183, 248, 55, 96, 216, 172, 96];
+
+fn pump_trade_data(discriminator: [u8; 8], token_amount: u64, sol_amount: u64) -> Vec<u8> {
+    // buy(amount, max_sol_cost) / sell(amount, min_sol_output)
+    let mut data = Vec::with_capacity(24);
+    data.extend_from_slice(&discriminator);
+    data.extend_from_slice(&token_amount.to_le_bytes());
+    data.extend_from_slice(&sol_amount.to_le_bytes());
+    data
+}
+
+/* ======================================================
+ * BondingCurve (73 bytes after the discriminator)
+ * ====================================================== */
+pub const BONDING_CURVE_LEN: usize = 73;
+
+#[repr(C, packed)]
+#[derive(Clone, Copy, Pod, Zeroable)]
+pub struct BondingCurve {
+    pub virtual_token_reserves: u64,
+    pub virtual_sol_reserves: u64,
+    pub real_token_reserves: u64,
+    pub real_sol_reserves: u64,
+    pub token_total_supply: u64,
+    /// set once the curve graduates; trading moves to the AMM
+    pub complete: u8,
+    pub creator: Pubkey,
+}
+
+const _: () = assert!(std::mem::size_of::<BondingCurve>() == BONDING_CURVE_LEN);
+
+impl BondingCurve {
+    /// Zero-copy view of a curve account that must be owned by the pump.fun program
+    pub fn load<'a>(curve: &'a AccountInfo, program: &Pubkey) -> Result<Ref<'a, Self>> {
+        require!(curve.owner == program, SolscopeError::InvalidPoolAccount);
+
+        let data = curve.try_borrow_data()?;
+        require!(
+            data.len() >= 8 + BONDING_CURVE_LEN && data[..8] == BONDING_CURVE_DISCRIMINATOR,
+            SolscopeError::InvalidPoolAccount
+        );
+        Ok(Ref::map(data, |data| {
+            bytemuck::from_bytes(&data[8..8 + BONDING_CURVE_LEN])
+        }))
+    }
+}
+
+/* ======================================================
+ * pump.fun bonding-curve accounts (used by execute_pump_trade)
+ * ====================================================== */
+#[derive(Accounts)]
+pub struct PumpFunAccounts<'info> {
+    /// CHECK: pump.fun program
+    #[account(address = pump_fun_program())]
+    pub pump_program: AccountInfo<'info>,
+
+    /// CHECK: Global config PDA ("global")
+    pub global: AccountInfo<'info>,
+    /// CHECK: Protocol fee recipient (checked by pump.fun against Global)
+    #[account(mut)]
+    pub fee_recipient: AccountInfo<'info>,
+    /// CHECK: Bonding curve PDA ("bonding-curve", mint)
+    #[account(mut)]
+    pub bonding_curve: AccountInfo<'info>,
+    /// CHECK: Bonding curve's token ATA
+    #[account(mut)]
+    pub associated_bonding_curve: AccountInfo<'info>,
+    /// CHECK: Creator fee vault PDA ("creator-vault", creator)
+    #[account(mut)]
+    pub creator_vault: AccountInfo<'info>,
+    /// CHECK: Anchor event authority PDA ("__event_authority")
+    pub event_authority: AccountInfo<'info>,
+}
+
+impl<'info> PumpFunAccounts<'info> {
+    /// Verify the PDAs against `mint` and that the curve is still trading
+    pub fn validate(&self, mint: &Pubkey) -> Result<()> {
+        let program = self.pump_program.key;
+        let curve = BondingCurve::load(&self.bonding_curve, program)?;
+        require!(curve.complete == 0, SolscopeError::PoolNotTradable);
+
+        let pda = |seeds: &[&[u8]]| Pubkey::find_program_address(seeds, program).0;
+        require!(
+            pda(&[b"global"]) == self.global.key()
+                && pda(&[b"bonding-curve", mint.as_ref()]) == self.bonding_curve.key()
+                && pda(&[b"creator-vault", curve.creator.as_ref()]) == self.creator_vault.key()
+                && pda(&[b"__event_authority"]) == self.event_authority.key(),
+            SolscopeError::InvalidPoolAccount
+        );
+        require!(
+            get_associated_token_address(self.bonding_curve.key, mint)
+                == self.associated_bonding_curve.key(),
+            SolscopeError::InvalidPoolAccount
+        );
+
+        Ok(())
+    }
+
+    /// buy: receive exactly `token_amount`, paying at most `sol_amount` lamports (fees included)
+    /// sell: spend `token_amount`, receiving at least `sol_amount` lamports
+    pub fn trade_ix(
+        &self,
+        is_buy: bool,
+        mint: &Pubkey,
+        user_token_account: &Pubkey,
+        user: &Pubkey,
+        token_amount: u64,
+        sol_amount: u64,
+    ) -> Instruction {
+        let mut accounts = vec![
+            AccountMeta::new_readonly(*self.global.key, false),
+            AccountMeta::new(*self.fee_recipient.key, false),
+            AccountMeta::new_readonly(*mint, false),
+            AccountMeta::new(*self.bonding_curve.key, false),
+            AccountMeta::new(*self.associated_bonding_curve.key, false),
+            AccountMeta::new(*user_token_account, false),
+            AccountMeta::new(*user, true),
+            AccountMeta::new_readonly(System::id(), false),
+        ];
+        // buy and sell order token program / creator vault differently
+        if is_buy {
+            accounts.push(AccountMeta::new_readonly(token::ID, false));
+            accounts.push(AccountMeta::new(*self.creator_vault.key, false));
+        } else {
+            accounts.push(AccountMeta::new(*self.creator_vault.key, false));
+            accounts.push(AccountMeta::new_readonly(token::ID, false));
+        }
+        accounts.push(AccountMeta::new_readonly(*self.event_authority.key, false));
+        accounts.push(AccountMeta::new_readonly(*self.pump_program.key, false));
+
+        let data = if is_buy {
+            pump_trade_data(BUY_DISCRIMINATOR, token_amount, sol_amount)
+        } else {
+            pump_trade_data(SELL_DISCRIMINATOR, token_amount, sol_amount)
+        };
+
+        Instruction {
+            program_id: self.pump_program.key(),
+            accounts,
+            data,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{put, TestAccount};
+
+    struct Curve {
+        mint: Pubkey,
+        creator: Pubkey,
+    }
+
+    impl Curve {
+        fn new() -> Self {
+            Self {
+                mint: Pubkey::new_unique(),
+                creator: Pubkey::new_unique(),
+            }
+        }
+
+        /// BondingCurve account bytes, fields at their on-chain offsets
+        fn curve_data(&self, complete: bool) -> Vec<u8> {
+            let mut data = vec![0u8; 8 + BONDING_CURVE_LEN];
+            put(&mut data, 0, &BONDING_CURVE_DISCRIMINATOR);
+            put(&mut data, 8, &1_073_000_000_000_000u64.to_le_bytes()); // virtual_token_reserves
+            put(&mut data, 16, &30_000_000_000u64.to_le_bytes()); // virtual_sol_reserves
+            put(&mut data, 24, &793_100_000_000_000u64.to_le_bytes()); // real_token_reserves
+            put(&mut data, 40, &1_000_000_000_000_000u64.to_le_bytes()); // token_total_supply
+            data[48] = complete as u8;
+            put(&mut data, 49, self.creator.as_ref());
+            data
+        }
+
+        /// The 7 pump.fun accounts, PDAs derived for this curve's mint / creator
+        fn accounts(&self) -> Vec<TestAccount> {
+            let program = pump_fun_program();
+            let pda = |seeds: &[&[u8]]| Pubkey::find_program_address(seeds, &program).0;
+            let curve = pda(&[b"bonding-curve", self.mint.as_ref()]);
+            let none = Pubkey::default();
+            vec![
+                TestAccount::with_key(program, none, vec![]),
+                TestAccount::with_key(pda(&[b"global"]), program, vec![]),
+                TestAccount::with_key(Pubkey::new_unique(), none, vec![]),
+                TestAccount::with_key(curve, program, self.curve_data(false)),
+                TestAccount::with_key(
+                    get_associated_token_address(&curve, &self.mint),
+                    token::ID,
+                    vec![],
+                ),
+                TestAccount::with_key(
+                    pda(&[b"creator-vault", self.creator.as_ref()]),
+                    none,
+                    vec![],
+                ),
+                TestAccount::with_key(pda(&[b"__event_authority"]), none, vec![]),
+            ]
+        }
+    }
+
+    fn pump_accounts<'a>(infos: &[AccountInfo<'a>]) -> PumpFunAccounts<'a> {
+        PumpFunAccounts {
+            pump_program: infos[0].clone(),
+            global: infos[1].clone(),
+            fee_recipient: infos[2].clone(),
+            bonding_curve: infos[3].clone(),
+            associated_bonding_curve: infos[4].clone(),
+            creator_vault: infos[5].clone(),
+            event_authority: infos[6].clone(),
+        }
+    }
+
+    fn validate(accounts: &mut [TestAccount], mint: &Pubkey) -> Result<()> {
+        let infos: Vec<AccountInfo> = accounts.iter_mut().map(TestAccount::info).collect();
+        pump_accounts(&infos).validate(mint)
+    }
+
+    #[test]
+    fn bonding_curve_decodes_at_on_chain_offsets() {
+        let curve = Curve::new();
+        let mut account = TestAccount::new(pump_fun_program(), curve.curve_data(true));
+        let info = account.info();
+        let state = BondingCurve::load(&info, &pump_fun_program()).unwrap();
+
+        assert_eq!({ state.virtual_token_reserves }, 1_073_000_000_000_000);
+        assert_eq!({ state.virtual_sol_reserves }, 30_000_000_000);
+        assert_eq!({ state.real_token_reserves }, 793_100_000_000_000);
+        assert_eq!({ state.real_sol_reserves }, 0);
+        assert_eq!({ state.token_total_supply }, 1_000_000_000_000_000);
+        assert_eq!({ state.complete }, 1);
+        assert_eq!({ state.creator }, curve.creator);
+    }
+
+    #[test]
+    fn bonding_curve_rejects_wrong_owner_discriminator_or_length() {
+        let curve = Curve::new();
+        let program = pump_fun_program();
+        let mut account = TestAccount::new(Pubkey::new_unique(), curve.curve_data(false));
+        assert!(BondingCurve::load(&account.info(), &program).is_err());
+
+        account.owner = program;
+        account.data.truncate(8 + BONDING_CURVE_LEN - 1);
+        assert!(BondingCurve::load(&account.info(), &program).is_err());
+
+        account.data = curve.curve_data(false);
+        account.data[0] ^= 1;
+        assert!(BondingCurve::load(&account.info(), &program).is_err());
+    }
+
+    #[test]
+    fn live_curve_for_its_mint_passes() {
+        let curve = Curve::new();
+        validate(&mut curve.accounts(), &curve.mint).unwrap();
+    }
+
+    #[test]
+    fn completed_curve_is_not_tradable() {
+        let curve = Curve::new();
+        let mut accounts = curve.accounts();
+        accounts[3].data = curve.curve_data(true);
+        let err = validate(&mut accounts, &curve.mint).unwrap_err();
+        assert_eq!(err, SolscopeError::PoolNotTradable.into());
+    }
+
+    #[test]
+    fn rejects_accounts_derived_for_another_mint_or_creator() {
+        let curve = Curve::new();
+
+        // the curve PDA and its ATA are tied to the traded mint
+        let err = validate(&mut curve.accounts(), &Pubkey::new_unique()).unwrap_err();
+        assert_eq!(err, SolscopeError::InvalidPoolAccount.into());
+
+        // global / curve ATA / creator vault / event authority
+        for index in [1, 4, 5, 6] {
+            let mut accounts = curve.accounts();
+            accounts[index].key = Pubkey::new_unique();
+            let err = validate(&mut accounts, &curve.mint).unwrap_err();
+            assert_eq!(
+                err,
+                SolscopeError::InvalidPoolAccount.into(),
+                "account {index}"
+            );
+        }
+
+        // creator vault of another creator than the one the curve records
+        let mut accounts = curve.accounts();
+        accounts[3].data = Curve {
+            mint: curve.mint,
+            creator: Pubkey::new_unique(),
+        }
+        .curve_data(false);
+        let err = validate(&mut accounts, &curve.mint).unwrap_err();
+        assert_eq!(err, SolscopeError::InvalidPoolAccount.into());
+
+        // curve account owned by another program
+        let mut accounts = curve.accounts();
+        accounts[3].owner = Pubkey::new_unique();
+        let err = validate(&mut accounts, &curve.mint).unwrap_err();
+        assert_eq!(err, SolscopeError::InvalidPoolAccount.into());
+    }
+
+    #[test]
+    fn trade_ix_orders_accounts_per_side() {
+        let curve = Curve::new();
+        let mut accounts = curve.accounts();
+        let infos: Vec<AccountInfo> = accounts.iter_mut().map(TestAccount::info).collect();
+        let pump = pump_accounts(&infos);
+        let (user_ata, user) = (Pubkey::new_unique(), Pubkey::new_unique());
+
+        let buy = pump.trade_ix(true, &curve.mint, &user_ata, &user, 1_000, 50);
+        assert_eq!(buy.data, pump_trade_data(BUY_DISCRIMINATOR, 1_000, 50));
+        assert_eq!(buy.accounts[8].pubkey, token::ID);
+        assert_eq!(buy.accounts[9].pubkey, *pump.creator_vault.key);
+
+        let sell = pump.trade_ix(false, &curve.mint, &user_ata, &user, 1_000, 50);
+        assert_eq!(sell.data, pump_trade_data(SELL_DISCRIMINATOR, 1_000, 50));
+        assert_eq!(sell.accounts[8].pubkey, *pump.creator_vault.key);
+        assert_eq!(sell.accounts[9].pubkey, token::ID);
+
+        for ix in [buy, sell] {
+            assert_eq!(ix.accounts.len(), 12);
+            assert_eq!(ix.accounts[3].pubkey, *pump.bonding_curve.key);
+            assert_eq!(ix.accounts[5].pubkey, user_ata);
+            assert!(ix.accounts[6].pubkey == user && ix.accounts[6].is_signer);
+        }
+    }
+}
@@ -0,0 +1,111 @@
+// programs/solscope/src/pyth.rs
+
+use std::cell::Ref;
+use std::str::FromStr;
+
+use anchor_lang::prelude::*;
+use bytemuck::{Pod, Zeroable};
+
+use crate::errors::SolscopeError;
+
+#[cfg(not(test))]
+pub fn pyth_oracle_program() -> Pubkey {
+    Pubkey::from_str("FsJ3A3u2vn5cTVofAjvy6y5kwABJAqYWpe4975bi2epH").unwrap()
+}
+
+/// Local stand-in owner for hand-built price accounts (unit tests)
+#[cfg(test)]
+pub fn pyth_oracle_program() -> Pubkey {
+    Pubkey::from_str("PythStandin11111111111111111111111111111111").unwrap()
+}
+
+/// Pyth price account magic / version / account type (price = 3)
+const PYTH_MAGIC: u32 = 0xa1b2_c3d4;
+const PYTH_VERSION: u32 = 2;
+const PYTH_ACCOUNT_TYPE_PRICE: u32 = 3;
+
+/// PriceStatus::Trading (aggregate is usable)
+const PYTH_STATUS_TRADING: u32 = 1;
+
+/* ======================================================
+ * Pyth price account header (leading 240 bytes, through
+ * the aggregate price; component prices are not read)
+ * ====================================================== */
+pub const PYTH_PRICE_HEADER_LEN: usize = 240;
+
+#[repr(C, packed)]
+#[derive(Clone, Copy, Pod, Zeroable)]
+pub struct PythPriceHeader {
+    pub magic: u32,
+    pub version: u32,
+    pub account_type: u32,
+    pub size: u32,
+    pub price_type: u32,
+    /// price = agg_price * 10^exponent
+    pub exponent: i32,
+    pub num_components: u32,
+    pub num_quoters: u32,
+    pub last_slot: u64,
+    pub valid_slot: u64,
+    pub ema_price: [i64; 3],
+    pub ema_conf: [i64; 3],
+    /// publish time of the aggregate (unix seconds)
+    pub timestamp: i64,
+    pub min_publishers: u8,
+    pub drv2: u8,
+    pub drv3: u16,
+    pub drv4: u32,
+    pub product: Pubkey,
+    pub next: Pubkey,
+    pub prev_slot: u64,
+    pub prev_price: i64,
+    pub prev_conf: u64,
+    pub prev_timestamp: i64,
+    pub agg_price: i64,
+    pub agg_conf: u64,
+    pub agg_status: u32,
+    pub agg_corp_act: u32,
+    pub agg_pub_slot: u64,
+}
+
+const _: () = assert!(std::mem::size_of::<PythPriceHeader>() == PYTH_PRICE_HEADER_LEN);
+
+impl PythPriceHeader {
+    /// Zero-copy view of a price account that must be owned by the Pyth oracle program
+    pub fn load<'a>(oracle: &'a AccountInfo) -> Result<Ref<'a, Self>> {
+        require!(
+            *oracle.owner == pyth_oracle_program(),
+            SolscopeError::InvalidOracleAccount
+        );
+
+        let data = oracle.try_borrow_data()?;
+        require!(
+            data.len() >= PYTH_PRICE_HEADER_LEN,
+            SolscopeError::InvalidOracleAccount
+        );
+
+        let price: Ref<Self> = Ref::map(data, |data| {
+            bytemuck::from_bytes(&data[..PYTH_PRICE_HEADER_LEN])
+        });
+        require!(
+            price.magic == PYTH_MAGIC
+                && price.version == PYTH_VERSION
+                && price.account_type == PYTH_ACCOUNT_TYPE_PRICE,
+            SolscopeError::InvalidOracleAccount
+        );
+        Ok(price)
+    }
+
+    /// (price, exponent) of a trading, positive aggregate published within `max_age_secs`
+    pub fn current_price(&self, now: i64, max_age_secs: u32) -> Result<(i64, i32)> {
+        let price = self.agg_price;
+        let age = now.saturating_sub(self.timestamp);
+        require!(
+            self.agg_status == PYTH_STATUS_TRADING
+                && price > 0
+                && age <= i64::from(max_age_secs),
+            SolscopeError::OraclePriceUnavailable
+        );
+        Ok((price, self.exponent))
+    }
+}
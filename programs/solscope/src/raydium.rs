@@ -0,0 +1,732 @@
+// programs/solscope/src/raydium.rs
+
+use std::cell::Ref;
+use std::str::FromStr;
+
+use std::collections::BTreeSet;
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::{AccountMeta, Instruction};
+use anchor_spl::token;
+use bytemuck::{Pod, Zeroable};
+
+use crate::dex::{DexAdapter, SwapMode};
+use crate::errors::SolscopeError;
+
+pub fn raydium_amm_program() -> Pubkey {
+    Pubkey::from_str("RVKd61ztZW9KQqkHn7kYk9Z3n5Vf3L7hPwrKyYVJZZz").unwrap()
+}
+
+fn raydium_swap_base_in_data(amount_in: u64, min_out: u64) -> Vec<u8> {
+    // Raydium v4 SwapBaseIn (commonly 9). You already used this.
+    let mut data = Vec::with_capacity(17);
+    data.push(9);
+    data.extend_from_slice(&amount_in.to_le_bytes());
+    data.extend_from_slice(&min_out.to_le_bytes());
+    data
+}
+
+fn raydium_swap_base_out_data(max_amount_in: u64, amount_out: u64) -> Vec<u8> {
+    // Raydium v4 SwapBaseOut (11)
+    let mut data = Vec::with_capacity(17);
+    data.push(11);
+    data.extend_from_slice(&max_amount_in.to_le_bytes());
+    data.extend_from_slice(&amount_out.to_le_bytes());
+    data
+}
+
+/* ======================================================
+ * Raydium v4 AmmInfo (752 bytes, no discriminator)
+ * Packed so it can be viewed straight over account data.
+ * ====================================================== */
+pub const AMM_INFO_LEN: usize = 752;
+
+/// AmmInfo.status values (subset solscope cares about)
+pub const AMM_STATUS_INITIALIZED: u64 = 1;
+pub const AMM_STATUS_SWAP_ONLY: u64 = 6;
+pub const AMM_STATUS_WAITING_TRADE: u64 = 7;
+
+#[repr(C, packed)]
+#[derive(Clone, Copy, Pod, Zeroable)]
+pub struct Fees {
+    pub min_separate_numerator: u64,
+    pub min_separate_denominator: u64,
+    pub trade_fee_numerator: u64,
+    pub trade_fee_denominator: u64,
+    pub pnl_numerator: u64,
+    pub pnl_denominator: u64,
+    pub swap_fee_numerator: u64,
+    pub swap_fee_denominator: u64,
+}
+
+#[repr(C, packed)]
+#[derive(Clone, Copy, Pod, Zeroable)]
+pub struct StateData {
+    pub need_take_pnl_coin: u64,
+    pub need_take_pnl_pc: u64,
+    pub total_pnl_pc: u64,
+    pub total_pnl_coin: u64,
+    pub pool_open_time: u64,
+    pub padding: [u64; 2],
+    pub orderbook_to_init_time: u64,
+    pub swap_coin_in_amount: u128,
+    pub swap_pc_out_amount: u128,
+    pub swap_acc_pc_fee: u64,
+    pub swap_pc_in_amount: u128,
+    pub swap_coin_out_amount: u128,
+    pub swap_acc_coin_fee: u64,
+}
+
+#[repr(C, packed)]
+#[derive(Clone, Copy, Pod, Zeroable)]
+pub struct AmmInfo {
+    pub status: u64,
+    pub nonce: u64,
+    pub order_num: u64,
+    pub depth: u64,
+    pub coin_decimals: u64,
+    pub pc_decimals: u64,
+    pub state: u64,
+    pub reset_flag: u64,
+    pub min_size: u64,
+    pub vol_max_cut_ratio: u64,
+    pub amount_wave: u64,
+    pub coin_lot_size: u64,
+    pub pc_lot_size: u64,
+    pub min_price_multiplier: u64,
+    pub max_price_multiplier: u64,
+    pub sys_decimal_value: u64,
+    pub fees: Fees,
+    pub state_data: StateData,
+    pub coin_vault: Pubkey,
+    pub pc_vault: Pubkey,
+    pub coin_vault_mint: Pubkey,
+    pub pc_vault_mint: Pubkey,
+    pub lp_mint: Pubkey,
+    pub open_orders: Pubkey,
+    pub market: Pubkey,
+    pub market_program: Pubkey,
+    pub target_orders: Pubkey,
+    pub padding1: [u64; 8],
+    pub amm_owner: Pubkey,
+    pub lp_amount: u64,
+    pub client_order_id: u64,
+    pub recent_epoch: u64,
+    pub padding2: u64,
+}
+
+const _: () = assert!(std::mem::size_of::<AmmInfo>() == AMM_INFO_LEN);
+
+impl AmmInfo {
+    /// View raw AmmInfo bytes (exact length, any alignment)
+    pub fn from_bytes(data: &[u8]) -> Result<&Self> {
+        bytemuck::try_from_bytes(data).map_err(|_| error!(SolscopeError::InvalidPoolAccount))
+    }
+
+    /// Zero-copy view of an AMM account that must be owned by the Raydium program
+    pub fn load<'a>(amm: &'a AccountInfo, amm_program: &Pubkey) -> Result<Ref<'a, Self>> {
+        require!(amm.owner == amm_program, SolscopeError::InvalidPoolAccount);
+
+        let data = amm.try_borrow_data()?;
+        Self::from_bytes(&data)?;
+        Ok(Ref::map(data, |data| bytemuck::from_bytes(data)))
+    }
+
+    pub fn open_time(&self) -> u64 {
+        self.state_data.pool_open_time
+    }
+
+    /// Swaps are live once initialized (or waiting and past open_time)
+    pub fn swap_enabled(&self, unix_timestamp: i64) -> bool {
+        match self.status {
+            AMM_STATUS_INITIALIZED | AMM_STATUS_SWAP_ONLY => true,
+            AMM_STATUS_WAITING_TRADE => unix_timestamp >= 0 && unix_timestamp as u64 >= self.open_time(),
+            _ => false,
+        }
+    }
+}
+
+/* ======================================================
+ * Pool orientation (which side holds the quote mint)
+ * Quote is wSOL for SOL trades, the source mint for token swaps.
+ * ====================================================== */
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum PoolOrientation {
+    /// coin = quote, pc = token (e.g. SOL/USDC)
+    QuoteCoin,
+    /// coin = token, pc = quote (most memecoin/SOL pools)
+    QuotePc,
+}
+
+impl PoolOrientation {
+    /// Raydium picks the swap direction from the user source mint;
+    /// true if that source is the pool's coin side.
+    pub fn input_is_coin(self, quote_is_input: bool) -> bool {
+        match self {
+            PoolOrientation::QuoteCoin => quote_is_input,
+            PoolOrientation::QuotePc => !quote_is_input,
+        }
+    }
+}
+
+impl AmmInfo {
+    /// Orientation of a quote/<mint> pool; errors if the pool doesn't pair exactly these
+    pub fn orientation(&self, quote_mint: &Pubkey, mint: &Pubkey) -> Result<PoolOrientation> {
+        let (coin_mint, pc_mint) = (self.coin_vault_mint, self.pc_vault_mint);
+        if coin_mint == *quote_mint && pc_mint == *mint {
+            Ok(PoolOrientation::QuoteCoin)
+        } else if coin_mint == *mint && pc_mint == *quote_mint {
+            Ok(PoolOrientation::QuotePc)
+        } else {
+            err!(SolscopeError::PoolMintMismatch)
+        }
+    }
+}
+
+/* ======================================================
+ * OpenBook / Serum v3 MarketState
+ * Account data is "serum" (5) + MarketState (376) + "padding" (7).
+ * ====================================================== */
+pub const MARKET_HEAD_PADDING: usize = 5;
+pub const MARKET_TAIL_PADDING: usize = 7;
+pub const MARKET_STATE_LEN: usize = 376;
+
+#[repr(C, packed)]
+#[derive(Clone, Copy, Pod, Zeroable)]
+pub struct MarketState {
+    pub account_flags: u64,
+    pub own_address: Pubkey,
+    pub vault_signer_nonce: u64,
+    pub coin_mint: Pubkey,
+    pub pc_mint: Pubkey,
+    pub coin_vault: Pubkey,
+    pub coin_deposits_total: u64,
+    pub coin_fees_accrued: u64,
+    pub pc_vault: Pubkey,
+    pub pc_deposits_total: u64,
+    pub pc_fees_accrued: u64,
+    pub pc_dust_threshold: u64,
+    pub req_q: Pubkey,
+    pub event_q: Pubkey,
+    pub bids: Pubkey,
+    pub asks: Pubkey,
+    pub coin_lot_size: u64,
+    pub pc_lot_size: u64,
+    pub fee_rate_bps: u64,
+    pub referrer_rebates_accrued: u64,
+}
+
+const _: () = assert!(std::mem::size_of::<MarketState>() == MARKET_STATE_LEN);
+
+impl MarketState {
+    /// View full market account bytes (head/tail padding included)
+    pub fn from_account_bytes(data: &[u8]) -> Result<&Self> {
+        require!(
+            data.len() == MARKET_HEAD_PADDING + MARKET_STATE_LEN + MARKET_TAIL_PADDING,
+            SolscopeError::InvalidPoolAccount
+        );
+        bytemuck::try_from_bytes(&data[MARKET_HEAD_PADDING..MARKET_HEAD_PADDING + MARKET_STATE_LEN])
+            .map_err(|_| error!(SolscopeError::InvalidPoolAccount))
+    }
+
+    /// Zero-copy view of a market account that must be owned by the market program
+    pub fn load<'a>(market: &'a AccountInfo, market_program: &Pubkey) -> Result<Ref<'a, Self>> {
+        require!(market.owner == market_program, SolscopeError::InvalidPoolAccount);
+
+        let data = market.try_borrow_data()?;
+        let own_address = Self::from_account_bytes(&data)?.own_address;
+        require!(own_address == market.key(), SolscopeError::InvalidPoolAccount);
+
+        Ok(Ref::map(data, |data| {
+            bytemuck::from_bytes(&data[MARKET_HEAD_PADDING..MARKET_HEAD_PADDING + MARKET_STATE_LEN])
+        }))
+    }
+
+    /// PDA-like signer the market program derives from its nonce
+    pub fn vault_signer(&self, market: &Pubkey, market_program: &Pubkey) -> Result<Pubkey> {
+        let nonce = self.vault_signer_nonce;
+        Pubkey::create_program_address(&[market.as_ref(), &nonce.to_le_bytes()], market_program)
+            .map_err(|_| error!(SolscopeError::InvalidPoolAccount))
+    }
+}
+
+/* ======================================================
+ * Raydium v4 swap accounts (DEX_RAYDIUM_V4 venue layout,
+ * passed through remaining_accounts in this order)
+ * ====================================================== */
+pub const RAYDIUM_V4_ACCOUNTS_LEN: usize = 15;
+
+#[derive(Accounts)]
+pub struct RaydiumPoolAccounts<'info> {
+    /// CHECK: Raydium AMM program
+    #[account(address = raydium_amm_program())]
+    pub amm_program: AccountInfo<'info>,
+
+    /// CHECK: AMM state
+    #[account(mut)]
+    pub amm: AccountInfo<'info>,
+    /// CHECK: AMM authority
+    pub amm_authority: AccountInfo<'info>,
+    /// CHECK: OpenOrders
+    #[account(mut)]
+    pub amm_open_orders: AccountInfo<'info>,
+    /// CHECK: TargetOrders
+    #[account(mut)]
+    pub amm_target_orders: AccountInfo<'info>,
+    /// CHECK: Pool coin vault (quote or token, see PoolOrientation)
+    #[account(mut)]
+    pub pool_coin_token_account: AccountInfo<'info>,
+    /// CHECK: Pool pc vault (the other side)
+    #[account(mut)]
+    pub pool_pc_token_account: AccountInfo<'info>,
+
+    /// CHECK: Serum/OpenBook program (must match AmmInfo.market_program)
+    pub serum_program: AccountInfo<'info>,
+    /// CHECK: Serum market
+    #[account(mut)]
+    pub serum_market: AccountInfo<'info>,
+    /// CHECK: Serum bids
+    #[account(mut)]
+    pub serum_bids: AccountInfo<'info>,
+    /// CHECK: Serum asks
+    #[account(mut)]
+    pub serum_asks: AccountInfo<'info>,
+    /// CHECK: Serum event queue
+    #[account(mut)]
+    pub serum_event_queue: AccountInfo<'info>,
+    /// CHECK: Serum coin vault
+    #[account(mut)]
+    pub serum_coin_vault: AccountInfo<'info>,
+    /// CHECK: Serum pc vault
+    #[account(mut)]
+    pub serum_pc_vault: AccountInfo<'info>,
+    /// CHECK: Serum vault signer
+    pub serum_vault_signer: AccountInfo<'info>,
+}
+
+impl<'info> RaydiumPoolAccounts<'info> {
+    /// Verify every pool/market account against AmmInfo + MarketState (no fake pools)
+    /// and return the pool orientation relative to `quote_mint`.
+    pub fn validate_pool(&self, quote_mint: &Pubkey, mint: &Pubkey) -> Result<PoolOrientation> {
+        let amm_info = AmmInfo::load(&self.amm, self.amm_program.key)?;
+        require!(
+            amm_info.swap_enabled(Clock::get()?.unix_timestamp),
+            SolscopeError::PoolNotTradable
+        );
+        require!(
+            amm_info.coin_vault == self.pool_coin_token_account.key()
+                && amm_info.pc_vault == self.pool_pc_token_account.key()
+                && amm_info.open_orders == self.amm_open_orders.key()
+                && amm_info.target_orders == self.amm_target_orders.key()
+                && amm_info.market == self.serum_market.key()
+                && amm_info.market_program == self.serum_program.key(),
+            SolscopeError::InvalidPoolAccount
+        );
+
+        // pool must pair the quote with exactly the traded mint (either side)
+        let orientation = amm_info.orientation(quote_mint, mint)?;
+        let (coin_mint, pc_mint) = (amm_info.coin_vault_mint, amm_info.pc_vault_mint);
+
+        // market accounts must belong to the AMM's market
+        let market = MarketState::load(&self.serum_market, self.serum_program.key)?;
+        require!(
+            market.coin_mint == coin_mint
+                && market.pc_mint == pc_mint
+                && market.bids == self.serum_bids.key()
+                && market.asks == self.serum_asks.key()
+                && market.event_q == self.serum_event_queue.key()
+                && market.coin_vault == self.serum_coin_vault.key()
+                && market.pc_vault == self.serum_pc_vault.key()
+                && market.vault_signer(self.serum_market.key, self.serum_program.key)?
+                    == self.serum_vault_signer.key(),
+            SolscopeError::InvalidPoolAccount
+        );
+
+        Ok(orientation)
+    }
+
+    /// Pool vaults as (takes our input, pays our output)
+    pub fn pool_vaults(
+        &self,
+        orientation: PoolOrientation,
+        quote_is_input: bool,
+    ) -> (&AccountInfo<'info>, &AccountInfo<'info>) {
+        if orientation.input_is_coin(quote_is_input) {
+            (&self.pool_coin_token_account, &self.pool_pc_token_account)
+        } else {
+            (&self.pool_pc_token_account, &self.pool_coin_token_account)
+        }
+    }
+
+    /// The vault taking our input must hold `input_mint`, the one paying out `output_mint`
+    pub fn check_vault_mints(
+        &self,
+        orientation: PoolOrientation,
+        input_mint: &Pubkey,
+        output_mint: &Pubkey,
+    ) -> Result<()> {
+        let (pool_source, pool_dest) = self.pool_vaults(orientation, true);
+        require!(
+            token::accessor::mint(pool_source)? == *input_mint
+                && token::accessor::mint(pool_dest)? == *output_mint,
+            SolscopeError::PoolMintMismatch
+        );
+        Ok(())
+    }
+}
+
+impl<'info> DexAdapter<'info> for RaydiumPoolAccounts<'info> {
+    fn load(accounts: &mut &'info [AccountInfo<'info>]) -> Result<Self> {
+        require!(
+            accounts.len() >= RAYDIUM_V4_ACCOUNTS_LEN,
+            SolscopeError::InvalidPoolAccount
+        );
+        RaydiumPoolAccounts::try_accounts(
+            &crate::ID,
+            accounts,
+            &[],
+            &mut RaydiumPoolAccountsBumps::default(),
+            &mut BTreeSet::new(),
+        )
+    }
+
+    fn program_id(&self) -> Pubkey {
+        self.amm_program.key()
+    }
+
+    fn pool_id(&self) -> Pubkey {
+        self.amm.key()
+    }
+
+    fn validate(&mut self, input_mint: &Pubkey, output_mint: &Pubkey) -> Result<()> {
+        // input mint plays the quote role; Raydium infers the direction from the
+        // user source mint, so orientation only decides which vault pays out
+        let orientation = self.validate_pool(input_mint, output_mint)?;
+        self.check_vault_mints(orientation, input_mint, output_mint)
+    }
+
+    /// Raydium v4 swap instruction (SwapBaseIn / SwapBaseOut share the account layout)
+    fn swap_ix(
+        &self,
+        user_source: &Pubkey,
+        user_dest: &Pubkey,
+        user_owner: &Pubkey,
+        mode: SwapMode,
+        amount_in: u64,
+        min_out: u64,
+    ) -> Result<Instruction> {
+        Ok(Instruction {
+            program_id: self.amm_program.key(),
+            accounts: vec![
+                AccountMeta::new_readonly(token::ID, false),
+                AccountMeta::new(*self.amm.key, false),
+                AccountMeta::new_readonly(*self.amm_authority.key, false),
+                AccountMeta::new(*self.amm_open_orders.key, false),
+                AccountMeta::new(*self.amm_target_orders.key, false),
+                AccountMeta::new(*self.pool_coin_token_account.key, false),
+                AccountMeta::new(*self.pool_pc_token_account.key, false),
+                AccountMeta::new_readonly(*self.serum_program.key, false),
+                AccountMeta::new(*self.serum_market.key, false),
+                AccountMeta::new(*self.serum_bids.key, false),
+                AccountMeta::new(*self.serum_asks.key, false),
+                AccountMeta::new(*self.serum_event_queue.key, false),
+                AccountMeta::new(*self.serum_coin_vault.key, false),
+                AccountMeta::new(*self.serum_pc_vault.key, false),
+                AccountMeta::new_readonly(*self.serum_vault_signer.key, false),
+                AccountMeta::new(*user_source, false),
+                AccountMeta::new(*user_dest, false),
+                AccountMeta::new_readonly(*user_owner, true),
+            ],
+            data: match mode {
+                SwapMode::ExactIn => raydium_swap_base_in_data(amount_in, min_out),
+                SwapMode::ExactOut => raydium_swap_base_out_data(amount_in, min_out),
+            },
+        })
+    }
+
+    fn cpi_accounts(&self) -> Vec<AccountInfo<'info>> {
+        self.to_account_infos()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{put, token_account_data, TestAccount};
+
+    fn key(s: &str) -> Pubkey {
+        Pubkey::from_str(s).unwrap()
+    }
+
+    /* mainnet SOL-USDC v4 pool (58oQCh...) and its OpenBook market (8BnEgH...),
+     * written field by field at their on-chain byte offsets */
+    const AMM: &str = "58oQChx4yWmvKdwLLZzBi4ChoCc2fqCUWBkwMihLYQo2";
+    const MARKET: &str = "8BnEgHoWFysVcuFFX7QztDmzuH8r5ZFvyP3sYwn1XTh6";
+    const MARKET_PROGRAM: &str = "srmqPvymJeFKQ4zGQed1GFppgkRHL9kaELCbyksJtPX";
+    const WSOL: &str = "So11111111111111111111111111111111111111112";
+    const USDC: &str = "EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v";
+    const COIN_VAULT: &str = "DQyrAcCrDXQ7NeoqGgDCZwBvWDcYmFCjSb9JtteuvPpz";
+    const PC_VAULT: &str = "HLmqeL62xR1QoZ1HKKbXRrdN1p3phKpxRMb2VVopvBBz";
+    const OPEN_ORDERS: &str = "HmiHHzq4Fym9e1D4qzLS6LDDM3tNsCTBPDWHTLZ763jY";
+    const TARGET_ORDERS: &str = "CZza3Ej4Mc58MnxWA385itCC9jCo3L1D7zc3LKy1bZMR";
+    const OPEN_TIME: u64 = 1_661_472_000;
+
+    fn sol_usdc_amm_bytes() -> Vec<u8> {
+        let mut data = vec![0u8; AMM_INFO_LEN];
+        put(&mut data, 0, &AMM_STATUS_SWAP_ONLY.to_le_bytes()); // status
+        put(&mut data, 8, &254u64.to_le_bytes()); // nonce
+        put(&mut data, 32, &9u64.to_le_bytes()); // coin_decimals
+        put(&mut data, 40, &6u64.to_le_bytes()); // pc_decimals
+        put(&mut data, 144, &25u64.to_le_bytes()); // fees.trade_fee_numerator
+        put(&mut data, 152, &10_000u64.to_le_bytes()); // fees.trade_fee_denominator
+        put(&mut data, 176, &25u64.to_le_bytes()); // fees.swap_fee_numerator
+        put(&mut data, 184, &10_000u64.to_le_bytes()); // fees.swap_fee_denominator
+        put(&mut data, 224, &OPEN_TIME.to_le_bytes()); // state_data.pool_open_time
+        put(&mut data, 336, key(COIN_VAULT).as_ref());
+        put(&mut data, 368, key(PC_VAULT).as_ref());
+        put(&mut data, 400, key(WSOL).as_ref()); // coin_vault_mint
+        put(&mut data, 432, key(USDC).as_ref()); // pc_vault_mint
+        put(&mut data, 496, key(OPEN_ORDERS).as_ref());
+        put(&mut data, 528, key(MARKET).as_ref());
+        put(&mut data, 560, key(MARKET_PROGRAM).as_ref());
+        put(&mut data, 592, key(TARGET_ORDERS).as_ref());
+        put(&mut data, 736, &600u64.to_le_bytes()); // recent_epoch
+        data
+    }
+
+    /// First nonce whose vault signer is off-curve, as the market program picks it
+    fn vault_signer_nonce(market: &Pubkey, program: &Pubkey) -> u64 {
+        (0u64..)
+            .find(|nonce| {
+                Pubkey::create_program_address(&[market.as_ref(), &nonce.to_le_bytes()], program)
+                    .is_ok()
+            })
+            .unwrap()
+    }
+
+    fn sol_usdc_market_bytes() -> Vec<u8> {
+        let market = key(MARKET);
+        let mut data = vec![0u8; MARKET_HEAD_PADDING + MARKET_STATE_LEN + MARKET_TAIL_PADDING];
+        put(&mut data, 0, b"serum");
+        put(&mut data, 5, &3u64.to_le_bytes()); // account_flags: Initialized | Market
+        put(&mut data, 13, market.as_ref()); // own_address
+        let nonce = vault_signer_nonce(&market, &key(MARKET_PROGRAM));
+        put(&mut data, 45, &nonce.to_le_bytes());
+        put(&mut data, 53, key(WSOL).as_ref()); // coin_mint
+        put(&mut data, 85, key(USDC).as_ref()); // pc_mint
+        put(&mut data, 349, &1_000_000u64.to_le_bytes()); // coin_lot_size
+        put(&mut data, 357, &1u64.to_le_bytes()); // pc_lot_size
+        put(&mut data, 365, &22u64.to_le_bytes()); // fee_rate_bps
+        put(&mut data, 381, b"padding");
+        data
+    }
+
+    #[test]
+    fn amm_info_decodes_sol_usdc_pool() {
+        let data = sol_usdc_amm_bytes();
+        let amm = AmmInfo::from_bytes(&data).unwrap();
+
+        assert_eq!({ amm.status }, AMM_STATUS_SWAP_ONLY);
+        assert_eq!({ amm.coin_decimals }, 9);
+        assert_eq!({ amm.pc_decimals }, 6);
+        assert_eq!({ amm.fees.trade_fee_numerator }, 25);
+        assert_eq!({ amm.fees.trade_fee_denominator }, 10_000);
+        assert_eq!({ amm.fees.swap_fee_numerator }, 25);
+        assert_eq!({ amm.fees.swap_fee_denominator }, 10_000);
+        assert_eq!(amm.open_time(), OPEN_TIME);
+        assert_eq!({ amm.coin_vault }, key(COIN_VAULT));
+        assert_eq!({ amm.pc_vault }, key(PC_VAULT));
+        assert_eq!({ amm.coin_vault_mint }, key(WSOL));
+        assert_eq!({ amm.pc_vault_mint }, key(USDC));
+        assert_eq!({ amm.open_orders }, key(OPEN_ORDERS));
+        assert_eq!({ amm.target_orders }, key(TARGET_ORDERS));
+        assert_eq!({ amm.market }, key(MARKET));
+        assert_eq!({ amm.market_program }, key(MARKET_PROGRAM));
+        assert_eq!({ amm.recent_epoch }, 600);
+
+        assert_eq!(std::mem::size_of::<Fees>(), 64);
+        assert_eq!(std::mem::size_of::<StateData>(), 144);
+        assert_eq!(std::mem::size_of::<AmmInfo>(), AMM_INFO_LEN);
+    }
+
+    #[test]
+    fn amm_info_rejects_wrong_length_or_owner() {
+        let data = sol_usdc_amm_bytes();
+        assert!(AmmInfo::from_bytes(&data[..AMM_INFO_LEN - 1]).is_err());
+
+        let mut amm = TestAccount::with_key(key(AMM), raydium_amm_program(), data);
+        assert!(AmmInfo::load(&amm.info(), &raydium_amm_program()).is_ok());
+        amm.owner = Pubkey::new_unique();
+        assert!(AmmInfo::load(&amm.info(), &raydium_amm_program()).is_err());
+    }
+
+    #[test]
+    fn swap_enabled_follows_status_and_open_time() {
+        let mut data = sol_usdc_amm_bytes();
+        let open = OPEN_TIME as i64;
+        assert!(AmmInfo::from_bytes(&data).unwrap().swap_enabled(0));
+
+        put(&mut data, 0, &AMM_STATUS_WAITING_TRADE.to_le_bytes());
+        let amm = AmmInfo::from_bytes(&data).unwrap();
+        assert!(!amm.swap_enabled(open - 1));
+        assert!(amm.swap_enabled(open));
+
+        // Disabled / WithdrawOnly / LiquidityOnly / OrderBookOnly never swap
+        for status in 2u64..=5 {
+            put(&mut data, 0, &status.to_le_bytes());
+            assert!(!AmmInfo::from_bytes(&data).unwrap().swap_enabled(open));
+        }
+    }
+
+    #[test]
+    fn market_state_decodes_sol_usdc_market() {
+        let data = sol_usdc_market_bytes();
+        let market = MarketState::from_account_bytes(&data).unwrap();
+
+        assert_eq!({ market.account_flags }, 3);
+        assert_eq!({ market.own_address }, key(MARKET));
+        assert_eq!({ market.coin_mint }, key(WSOL));
+        assert_eq!({ market.pc_mint }, key(USDC));
+        assert_eq!({ market.coin_lot_size }, 1_000_000);
+        assert_eq!({ market.pc_lot_size }, 1);
+        assert_eq!({ market.fee_rate_bps }, 22);
+        assert_eq!(std::mem::size_of::<MarketState>(), MARKET_STATE_LEN);
+
+        // head / tail padding is required around the state
+        assert!(MarketState::from_account_bytes(&data[MARKET_HEAD_PADDING..]).is_err());
+    }
+
+    #[test]
+    fn market_state_load_checks_owner_and_own_address() {
+        let program = key(MARKET_PROGRAM);
+        let mut market = TestAccount::with_key(key(MARKET), program, sol_usdc_market_bytes());
+        {
+            let info = market.info();
+            let state = MarketState::load(&info, &program).unwrap();
+            let signer = state.vault_signer(info.key, &program).unwrap();
+            let nonce = state.vault_signer_nonce;
+            let expected = Pubkey::create_program_address(
+                &[info.key.as_ref(), &nonce.to_le_bytes()],
+                &program,
+            )
+            .unwrap();
+            assert_eq!(signer, expected);
+        }
+
+        market.key = Pubkey::new_unique();
+        assert!(MarketState::load(&market.info(), &program).is_err());
+
+        market.key = key(MARKET);
+        market.owner = Pubkey::new_unique();
+        assert!(MarketState::load(&market.info(), &program).is_err());
+    }
+
+    /* ========== Orientation + vault selection (wSOL as coin / as pc) ========== */
+    const BONK: &str = "DezXAZ8z7PnrnRJjz3wXBoRgixCa6xjnB7YaB1pPB263";
+
+    /// The 15 venue accounts in RAYDIUM_V4_ACCOUNTS_LEN order, with token
+    /// vaults holding `coin_mint` / `pc_mint`
+    fn venue_accounts(coin_mint: &Pubkey, pc_mint: &Pubkey) -> Vec<TestAccount> {
+        let authority = Pubkey::new_unique();
+        (0..RAYDIUM_V4_ACCOUNTS_LEN)
+            .map(|i| match i {
+                0 => TestAccount::with_key(raydium_amm_program(), Pubkey::default(), vec![]),
+                5 => TestAccount::with_key(
+                    Pubkey::new_unique(),
+                    token::ID,
+                    token_account_data(coin_mint, &authority, 0),
+                ),
+                6 => TestAccount::with_key(
+                    Pubkey::new_unique(),
+                    token::ID,
+                    token_account_data(pc_mint, &authority, 0),
+                ),
+                _ => TestAccount::with_key(Pubkey::new_unique(), Pubkey::default(), vec![]),
+            })
+            .collect()
+    }
+
+    fn amm_with_mints(coin_mint: &Pubkey, pc_mint: &Pubkey) -> Vec<u8> {
+        let mut data = sol_usdc_amm_bytes();
+        put(&mut data, 400, coin_mint.as_ref());
+        put(&mut data, 432, pc_mint.as_ref());
+        data
+    }
+
+    #[test]
+    fn wsol_as_coin_pool_selects_vaults_for_both_sides() {
+        let (wsol, usdc) = (key(WSOL), key(USDC));
+        let data = sol_usdc_amm_bytes();
+        let amm = AmmInfo::from_bytes(&data).unwrap();
+
+        let mut accounts = venue_accounts(&wsol, &usdc);
+        let infos: Vec<AccountInfo> = accounts.iter_mut().map(TestAccount::info).collect();
+        let mut remaining: &[AccountInfo] = &infos;
+        let pool = RaydiumPoolAccounts::load(&mut remaining).unwrap();
+
+        // BUY: wSOL in through the coin vault, USDC out of the pc vault
+        let buy = amm.orientation(&wsol, &usdc).unwrap();
+        assert_eq!(buy, PoolOrientation::QuoteCoin);
+        let (source, dest) = pool.pool_vaults(buy, true);
+        assert_eq!(source.key, pool.pool_coin_token_account.key);
+        assert_eq!(dest.key, pool.pool_pc_token_account.key);
+        pool.check_vault_mints(buy, &wsol, &usdc).unwrap();
+
+        // SELL: the token is the quote-side input, so the pc vault takes it
+        let sell = amm.orientation(&usdc, &wsol).unwrap();
+        assert_eq!(sell, PoolOrientation::QuotePc);
+        let (source, dest) = pool.pool_vaults(sell, true);
+        assert_eq!(source.key, pool.pool_pc_token_account.key);
+        assert_eq!(dest.key, pool.pool_coin_token_account.key);
+        pool.check_vault_mints(sell, &usdc, &wsol).unwrap();
+    }
+
+    #[test]
+    fn wsol_as_pc_pool_selects_vaults_for_both_sides() {
+        let (wsol, bonk) = (key(WSOL), key(BONK));
+        let data = amm_with_mints(&bonk, &wsol);
+        let amm = AmmInfo::from_bytes(&data).unwrap();
+
+        let mut accounts = venue_accounts(&bonk, &wsol);
+        let infos: Vec<AccountInfo> = accounts.iter_mut().map(TestAccount::info).collect();
+        let mut remaining: &[AccountInfo] = &infos;
+        let pool = RaydiumPoolAccounts::load(&mut remaining).unwrap();
+
+        // BUY: wSOL in through the pc vault, BONK out of the coin vault
+        let buy = amm.orientation(&wsol, &bonk).unwrap();
+        assert_eq!(buy, PoolOrientation::QuotePc);
+        let (source, dest) = pool.pool_vaults(buy, true);
+        assert_eq!(source.key, pool.pool_pc_token_account.key);
+        assert_eq!(dest.key, pool.pool_coin_token_account.key);
+        pool.check_vault_mints(buy, &wsol, &bonk).unwrap();
+
+        // SELL: BONK in through the coin vault, wSOL out of the pc vault
+        let sell = amm.orientation(&bonk, &wsol).unwrap();
+        assert_eq!(sell, PoolOrientation::QuoteCoin);
+        let (source, dest) = pool.pool_vaults(sell, true);
+        assert_eq!(source.key, pool.pool_coin_token_account.key);
+        assert_eq!(dest.key, pool.pool_pc_token_account.key);
+        pool.check_vault_mints(sell, &bonk, &wsol).unwrap();
+    }
+
+    #[test]
+    fn mint_mismatch_fails_with_pool_mint_mismatch() {
+        let (wsol, usdc, bonk) = (key(WSOL), key(USDC), key(BONK));
+        let data = sol_usdc_amm_bytes();
+        let amm = AmmInfo::from_bytes(&data).unwrap();
+
+        // pool doesn't pair the traded mint at all
+        let err = amm.orientation(&wsol, &bonk).unwrap_err();
+        assert_eq!(err, SolscopeError::PoolMintMismatch.into());
+
+        // AmmInfo says wSOL/USDC but the passed coin vault holds another mint
+        let mut accounts = venue_accounts(&bonk, &usdc);
+        let infos: Vec<AccountInfo> = accounts.iter_mut().map(TestAccount::info).collect();
+        let mut remaining: &[AccountInfo] = &infos;
+        let pool = RaydiumPoolAccounts::load(&mut remaining).unwrap();
+
+        let buy = amm.orientation(&wsol, &usdc).unwrap();
+        let err = pool.check_vault_mints(buy, &wsol, &usdc).unwrap_err();
+        assert_eq!(err, SolscopeError::PoolMintMismatch.into());
+    }
+}
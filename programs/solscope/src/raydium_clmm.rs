@@ -0,0 +1,507 @@
+// programs/solscope/src/raydium_clmm.rs
+
+use std::cell::Ref;
+use std::collections::BTreeSet;
+use std::str::FromStr;
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::{AccountMeta, Instruction};
+use anchor_spl::token;
+use bytemuck::{Pod, Zeroable};
+
+use crate::dex::{memo_program_id, token_2022_program_id, DexAdapter, SwapMode};
+use crate::errors::SolscopeError;
+// both Raydium programs name their pool account `PoolState`
+use crate::raydium_cpmm::POOL_STATE_DISCRIMINATOR;
+
+pub fn raydium_clmm_program() -> Pubkey {
+    Pubkey::from_str("CAMMCzo5YL8w4VFF8KVHrK22GGUsp5VTaW7grrKgrWqK").unwrap()
+}
+
+/// Anchor discriminator of swap_v2 (sha256("global:swap_v2")[..8])
+const SWAP_V2_DISCRIMINATOR: [u8; 8] = [43, 4, 237, 11, 26, 201, 30, 98];
+
+fn clmm_swap_v2_data(
+    amount: u64,
+    other_amount_threshold: u64,
+    sqrt_price_limit_x64: u128,
+    is_base_input: bool,
+) -> Vec<u8> {
+    let mut data = Vec::with_capacity(41);
+    data.extend_from_slice(&SWAP_V2_DISCRIMINATOR);
+    data.extend_from_slice(&amount.to_le_bytes());
+    data.extend_from_slice(&other_amount_threshold.to_le_bytes());
+    data.extend_from_slice(&sqrt_price_limit_x64.to_le_bytes());
+    data.push(is_base_input as u8);
+    data
+}
+
+/* ======================================================
+ * CLMM PoolState header (leading 389 bytes after the discriminator;
+ * rewards, bitmap and the rest of the account are not needed here)
+ * ====================================================== */
+pub const CLMM_POOL_STATE_HEADER_LEN: usize = 389;
+
+/// PoolState.status bit that disables swaps
+pub const CLMM_STATUS_SWAP_DISABLED: u8 = 1 << 4;
+
+#[repr(C, packed)]
+#[derive(Clone, Copy, Pod, Zeroable)]
+pub struct ClmmPoolStateHeader {
+    pub bump: u8,
+    pub amm_config: Pubkey,
+    pub owner: Pubkey,
+    pub token_mint_0: Pubkey,
+    pub token_mint_1: Pubkey,
+    pub token_vault_0: Pubkey,
+    pub token_vault_1: Pubkey,
+    pub observation_key: Pubkey,
+    pub mint_decimals_0: u8,
+    pub mint_decimals_1: u8,
+    pub tick_spacing: u16,
+    pub liquidity: u128,
+    pub sqrt_price_x64: u128,
+    pub tick_current: i32,
+    pub padding3: u16,
+    pub padding4: u16,
+    pub fee_growth_global_0_x64: u128,
+    pub fee_growth_global_1_x64: u128,
+    pub protocol_fees_token_0: u64,
+    pub protocol_fees_token_1: u64,
+    pub swap_in_amount_token_0: u128,
+    pub swap_out_amount_token_1: u128,
+    pub swap_in_amount_token_1: u128,
+    pub swap_out_amount_token_0: u128,
+    pub status: u8,
+    pub padding: [u8; 7],
+}
+
+const _: () = assert!(std::mem::size_of::<ClmmPoolStateHeader>() == CLMM_POOL_STATE_HEADER_LEN);
+
+impl ClmmPoolStateHeader {
+    /// Zero-copy view of a pool account that must be owned by the CLMM program
+    pub fn load<'a>(pool: &'a AccountInfo, clmm_program: &Pubkey) -> Result<Ref<'a, Self>> {
+        require!(pool.owner == clmm_program, SolscopeError::InvalidPoolAccount);
+
+        let data = pool.try_borrow_data()?;
+        require!(
+            data.len() >= 8 + CLMM_POOL_STATE_HEADER_LEN && data[..8] == POOL_STATE_DISCRIMINATOR,
+            SolscopeError::InvalidPoolAccount
+        );
+        Ok(Ref::map(data, |data| {
+            bytemuck::from_bytes(&data[8..8 + CLMM_POOL_STATE_HEADER_LEN])
+        }))
+    }
+
+    pub fn swap_enabled(&self) -> bool {
+        self.status & CLMM_STATUS_SWAP_DISABLED == 0
+    }
+}
+
+/// Tick arrays and the bitmap extension both store their pool right after the discriminator
+fn belongs_to_pool(account: &AccountInfo, clmm_program: &Pubkey, pool: &Pubkey) -> Result<bool> {
+    if account.owner != clmm_program {
+        return Ok(false);
+    }
+    let data = account.try_borrow_data()?;
+    Ok(data.len() >= 40 && data[8..40] == pool.as_ref()[..])
+}
+
+/* ======================================================
+ * CLMM swap_v2 accounts (DEX_RAYDIUM_CLMM venue layout,
+ * passed through remaining_accounts in this order).
+ * Every account after these is a tick array (optionally led by
+ * the bitmap extension), so CLMM must be the last venue listed.
+ * ====================================================== */
+pub const RAYDIUM_CLMM_ACCOUNTS_LEN: usize = 10;
+
+#[derive(Accounts)]
+pub struct RaydiumClmmAccounts<'info> {
+    /// CHECK: Raydium CLMM program
+    #[account(address = raydium_clmm_program())]
+    pub clmm_program: AccountInfo<'info>,
+
+    /// CHECK: AmmConfig (fee tier)
+    pub amm_config: AccountInfo<'info>,
+    /// CHECK: PoolState
+    #[account(mut)]
+    pub pool_state: AccountInfo<'info>,
+    /// CHECK: Pool vault taking our input
+    #[account(mut)]
+    pub input_vault: AccountInfo<'info>,
+    /// CHECK: Pool vault paying our output
+    #[account(mut)]
+    pub output_vault: AccountInfo<'info>,
+    /// CHECK: Oracle observation state
+    #[account(mut)]
+    pub observation_state: AccountInfo<'info>,
+    /// CHECK: Token-2022 program
+    #[account(address = token_2022_program_id())]
+    pub token_program_2022: AccountInfo<'info>,
+    /// CHECK: Memo program
+    #[account(address = memo_program_id())]
+    pub memo_program: AccountInfo<'info>,
+    /// CHECK: Input mint
+    pub input_vault_mint: AccountInfo<'info>,
+    /// CHECK: Output mint
+    pub output_vault_mint: AccountInfo<'info>,
+}
+
+/// CLMM venue: fixed accounts + trailing tick arrays + price limit
+pub struct RaydiumClmmVenue<'info> {
+    pub accounts: RaydiumClmmAccounts<'info>,
+    pub tick_arrays: &'info [AccountInfo<'info>],
+    /// Q64.64 sqrt price the swap may not cross (0 = no limit)
+    pub sqrt_price_limit_x64: u128,
+}
+
+impl<'info> RaydiumClmmVenue<'info> {
+    /// Load the venue with a price limit; consumes every remaining account
+    pub fn load_with_price_limit(
+        accounts: &mut &'info [AccountInfo<'info>],
+        sqrt_price_limit_x64: u128,
+    ) -> Result<Self> {
+        require!(
+            accounts.len() > RAYDIUM_CLMM_ACCOUNTS_LEN,
+            SolscopeError::InvalidPoolAccount
+        );
+        let fixed = RaydiumClmmAccounts::try_accounts(
+            &crate::ID,
+            accounts,
+            &[],
+            &mut RaydiumClmmAccountsBumps::default(),
+            &mut BTreeSet::new(),
+        )?;
+
+        // remaining accounts are the tick arrays (at least one)
+        let tick_arrays = *accounts;
+        *accounts = &[];
+
+        Ok(Self {
+            accounts: fixed,
+            tick_arrays,
+            sqrt_price_limit_x64,
+        })
+    }
+}
+
+impl<'info> DexAdapter<'info> for RaydiumClmmVenue<'info> {
+    fn load(accounts: &mut &'info [AccountInfo<'info>]) -> Result<Self> {
+        Self::load_with_price_limit(accounts, 0)
+    }
+
+    fn program_id(&self) -> Pubkey {
+        self.accounts.clmm_program.key()
+    }
+
+    fn pool_id(&self) -> Pubkey {
+        self.accounts.pool_state.key()
+    }
+
+    fn validate(&mut self, input_mint: &Pubkey, output_mint: &Pubkey) -> Result<()> {
+        let accounts = &self.accounts;
+        let program = accounts.clmm_program.key;
+        let pool = ClmmPoolStateHeader::load(&accounts.pool_state, program)?;
+        require!(pool.swap_enabled(), SolscopeError::PoolNotTradable);
+        require!(
+            pool.amm_config == accounts.amm_config.key()
+                && pool.observation_key == accounts.observation_state.key(),
+            SolscopeError::InvalidPoolAccount
+        );
+
+        // pool must pair input with exactly the output mint (either side)
+        let (input_vault, output_vault) =
+            if pool.token_mint_0 == *input_mint && pool.token_mint_1 == *output_mint {
+                (pool.token_vault_0, pool.token_vault_1)
+            } else if pool.token_mint_1 == *input_mint && pool.token_mint_0 == *output_mint {
+                (pool.token_vault_1, pool.token_vault_0)
+            } else {
+                return err!(SolscopeError::PoolMintMismatch);
+            };
+
+        require!(
+            accounts.input_vault_mint.key() == *input_mint
+                && accounts.output_vault_mint.key() == *output_mint,
+            SolscopeError::PoolMintMismatch
+        );
+        require!(
+            accounts.input_vault.key() == input_vault
+                && accounts.output_vault.key() == output_vault,
+            SolscopeError::InvalidPoolAccount
+        );
+
+        // tick arrays must belong to this pool
+        for tick_array in self.tick_arrays {
+            require!(
+                belongs_to_pool(tick_array, program, accounts.pool_state.key)?,
+                SolscopeError::InvalidPoolAccount
+            );
+        }
+
+        Ok(())
+    }
+
+    /// CLMM swap_v2: ExactIn passes (amount_in, min_out), ExactOut (amount_out, max_in)
+    fn swap_ix(
+        &self,
+        user_source: &Pubkey,
+        user_dest: &Pubkey,
+        user_owner: &Pubkey,
+        mode: SwapMode,
+        amount_in: u64,
+        min_out: u64,
+    ) -> Result<Instruction> {
+        let accounts = &self.accounts;
+        let mut metas = vec![
+            AccountMeta::new_readonly(*user_owner, true),
+            AccountMeta::new_readonly(*accounts.amm_config.key, false),
+            AccountMeta::new(*accounts.pool_state.key, false),
+            AccountMeta::new(*user_source, false),
+            AccountMeta::new(*user_dest, false),
+            AccountMeta::new(*accounts.input_vault.key, false),
+            AccountMeta::new(*accounts.output_vault.key, false),
+            AccountMeta::new(*accounts.observation_state.key, false),
+            AccountMeta::new_readonly(token::ID, false),
+            AccountMeta::new_readonly(*accounts.token_program_2022.key, false),
+            AccountMeta::new_readonly(*accounts.memo_program.key, false),
+            AccountMeta::new_readonly(*accounts.input_vault_mint.key, false),
+            AccountMeta::new_readonly(*accounts.output_vault_mint.key, false),
+        ];
+        metas.extend(
+            self.tick_arrays
+                .iter()
+                .map(|tick_array| AccountMeta::new(*tick_array.key, false)),
+        );
+
+        let data = match mode {
+            SwapMode::ExactIn => {
+                clmm_swap_v2_data(amount_in, min_out, self.sqrt_price_limit_x64, true)
+            }
+            SwapMode::ExactOut => {
+                clmm_swap_v2_data(min_out, amount_in, self.sqrt_price_limit_x64, false)
+            }
+        };
+
+        Ok(Instruction {
+            program_id: accounts.clmm_program.key(),
+            accounts: metas,
+            data,
+        })
+    }
+
+    fn cpi_accounts(&self) -> Vec<AccountInfo<'info>> {
+        let mut infos = self.accounts.to_account_infos();
+        infos.extend(self.tick_arrays.iter().cloned());
+        infos
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{put, TestAccount};
+
+    struct Pool {
+        pool_state: Pubkey,
+        amm_config: Pubkey,
+        observation: Pubkey,
+        mints: [Pubkey; 2],
+        vaults: [Pubkey; 2],
+    }
+
+    impl Pool {
+        fn new() -> Self {
+            Self {
+                pool_state: Pubkey::new_unique(),
+                amm_config: Pubkey::new_unique(),
+                observation: Pubkey::new_unique(),
+                mints: [Pubkey::new_unique(), Pubkey::new_unique()],
+                vaults: [Pubkey::new_unique(), Pubkey::new_unique()],
+            }
+        }
+
+        /// PoolState account bytes, fields at their on-chain offsets
+        fn pool_state_data(&self, status: u8) -> Vec<u8> {
+            let mut data = vec![0u8; 8 + CLMM_POOL_STATE_HEADER_LEN];
+            put(&mut data, 0, &POOL_STATE_DISCRIMINATOR);
+            data[8] = 255; // bump
+            put(&mut data, 9, self.amm_config.as_ref());
+            put(&mut data, 73, self.mints[0].as_ref());
+            put(&mut data, 105, self.mints[1].as_ref());
+            put(&mut data, 137, self.vaults[0].as_ref());
+            put(&mut data, 169, self.vaults[1].as_ref());
+            put(&mut data, 201, self.observation.as_ref());
+            data[233] = 9; // mint_decimals_0
+            data[234] = 6; // mint_decimals_1
+            put(&mut data, 235, &60u16.to_le_bytes()); // tick_spacing
+            put(&mut data, 253, &(1u128 << 64).to_le_bytes()); // sqrt_price_x64
+            put(&mut data, 269, &(-12i32).to_le_bytes()); // tick_current
+            data[389] = status;
+            data
+        }
+
+        fn tick_array(&self) -> TestAccount {
+            let mut data = vec![0u8; 40];
+            put(&mut data, 8, self.pool_state.as_ref());
+            TestAccount::new(raydium_clmm_program(), data)
+        }
+
+        /// The 10 fixed accounts for a swap of side `input`, then one tick array
+        fn accounts(&self, input: usize) -> Vec<TestAccount> {
+            let program = raydium_clmm_program();
+            let output = 1 - input;
+            let none = Pubkey::default();
+            vec![
+                TestAccount::with_key(program, none, vec![]),
+                TestAccount::with_key(self.amm_config, program, vec![]),
+                TestAccount::with_key(self.pool_state, program, self.pool_state_data(0)),
+                TestAccount::with_key(self.vaults[input], none, vec![]),
+                TestAccount::with_key(self.vaults[output], none, vec![]),
+                TestAccount::with_key(self.observation, program, vec![]),
+                TestAccount::with_key(token_2022_program_id(), none, vec![]),
+                TestAccount::with_key(memo_program_id(), none, vec![]),
+                TestAccount::with_key(self.mints[input], none, vec![]),
+                TestAccount::with_key(self.mints[output], none, vec![]),
+                self.tick_array(),
+            ]
+        }
+    }
+
+    fn validate(accounts: &mut [TestAccount], input: &Pubkey, output: &Pubkey) -> Result<()> {
+        let infos: Vec<AccountInfo> = accounts.iter_mut().map(TestAccount::info).collect();
+        let mut remaining: &[AccountInfo] = &infos;
+        let mut venue = RaydiumClmmVenue::load(&mut remaining)?;
+        assert!(remaining.is_empty());
+        venue.validate(input, output)
+    }
+
+    #[test]
+    fn pool_state_header_decodes_at_on_chain_offsets() {
+        let pool = Pool::new();
+        let mut account = TestAccount::with_key(
+            pool.pool_state,
+            raydium_clmm_program(),
+            pool.pool_state_data(CLMM_STATUS_SWAP_DISABLED),
+        );
+        let info = account.info();
+        let state = ClmmPoolStateHeader::load(&info, &raydium_clmm_program()).unwrap();
+
+        assert_eq!({ state.amm_config }, pool.amm_config);
+        assert_eq!({ state.token_mint_0 }, pool.mints[0]);
+        assert_eq!({ state.token_mint_1 }, pool.mints[1]);
+        assert_eq!({ state.token_vault_0 }, pool.vaults[0]);
+        assert_eq!({ state.token_vault_1 }, pool.vaults[1]);
+        assert_eq!({ state.observation_key }, pool.observation);
+        assert_eq!({ state.mint_decimals_0 }, 9);
+        assert_eq!({ state.mint_decimals_1 }, 6);
+        assert_eq!({ state.tick_spacing }, 60);
+        assert_eq!({ state.sqrt_price_x64 }, 1u128 << 64);
+        assert_eq!({ state.tick_current }, -12);
+        assert!(!state.swap_enabled());
+    }
+
+    #[test]
+    fn pool_state_rejects_wrong_owner_discriminator_or_length() {
+        let pool = Pool::new();
+        let program = raydium_clmm_program();
+        let mut account = TestAccount::with_key(
+            pool.pool_state,
+            Pubkey::new_unique(),
+            pool.pool_state_data(0),
+        );
+        assert!(ClmmPoolStateHeader::load(&account.info(), &program).is_err());
+
+        account.owner = program;
+        account.data.truncate(8 + CLMM_POOL_STATE_HEADER_LEN - 1);
+        assert!(ClmmPoolStateHeader::load(&account.info(), &program).is_err());
+
+        account.data = pool.pool_state_data(0);
+        account.data[0] ^= 1;
+        assert!(ClmmPoolStateHeader::load(&account.info(), &program).is_err());
+    }
+
+    #[test]
+    fn valid_pool_swaps_in_both_directions() {
+        let pool = Pool::new();
+        validate(&mut pool.accounts(0), &pool.mints[0], &pool.mints[1]).unwrap();
+        validate(&mut pool.accounts(1), &pool.mints[1], &pool.mints[0]).unwrap();
+    }
+
+    #[test]
+    fn load_requires_at_least_one_tick_array() {
+        let pool = Pool::new();
+        let mut accounts = pool.accounts(0);
+        accounts.pop();
+        let err = validate(&mut accounts, &pool.mints[0], &pool.mints[1]).unwrap_err();
+        assert_eq!(err, SolscopeError::InvalidPoolAccount.into());
+    }
+
+    #[test]
+    fn rejects_unrelated_mints_and_disabled_pools() {
+        let pool = Pool::new();
+
+        let err =
+            validate(&mut pool.accounts(0), &pool.mints[0], &Pubkey::new_unique()).unwrap_err();
+        assert_eq!(err, SolscopeError::PoolMintMismatch.into());
+
+        // vaults passed for 0 -> 1 while the swap claims 1 -> 0
+        let err = validate(&mut pool.accounts(0), &pool.mints[1], &pool.mints[0]).unwrap_err();
+        assert_eq!(err, SolscopeError::PoolMintMismatch.into());
+
+        let mut accounts = pool.accounts(0);
+        accounts[2].data = pool.pool_state_data(CLMM_STATUS_SWAP_DISABLED);
+        let err = validate(&mut accounts, &pool.mints[0], &pool.mints[1]).unwrap_err();
+        assert_eq!(err, SolscopeError::PoolNotTradable.into());
+
+        // other status bits (e.g. liquidity changes) don't stop swaps
+        let mut accounts = pool.accounts(0);
+        accounts[2].data = pool.pool_state_data(!CLMM_STATUS_SWAP_DISABLED);
+        validate(&mut accounts, &pool.mints[0], &pool.mints[1]).unwrap();
+    }
+
+    #[test]
+    fn rejects_accounts_that_do_not_match_the_pool() {
+        let pool = Pool::new();
+
+        // amm config / vaults / observation
+        for index in [1, 3, 4, 5] {
+            let mut accounts = pool.accounts(0);
+            accounts[index].key = Pubkey::new_unique();
+            let err = validate(&mut accounts, &pool.mints[0], &pool.mints[1]).unwrap_err();
+            assert_eq!(
+                err,
+                SolscopeError::InvalidPoolAccount.into(),
+                "account {index}"
+            );
+        }
+
+        // both vaults swapped for each other
+        let mut accounts = pool.accounts(0);
+        accounts.swap(3, 4);
+        let err = validate(&mut accounts, &pool.mints[0], &pool.mints[1]).unwrap_err();
+        assert_eq!(err, SolscopeError::InvalidPoolAccount.into());
+
+        // pool, Token-2022 and memo programs are pinned by address
+        for index in [0, 6, 7] {
+            let mut accounts = pool.accounts(0);
+            accounts[index].key = Pubkey::new_unique();
+            assert!(validate(&mut accounts, &pool.mints[0], &pool.mints[1]).is_err());
+        }
+    }
+
+    #[test]
+    fn tick_arrays_must_belong_to_the_pool() {
+        let pool = Pool::new();
+        let other = Pool::new();
+
+        // tick array of another pool, then one owned by another program
+        let mut foreign_owner = pool.tick_array();
+        foreign_owner.owner = Pubkey::new_unique();
+        for tick_array in [other.tick_array(), foreign_owner] {
+            let mut accounts = pool.accounts(0);
+            accounts.push(tick_array);
+            let err = validate(&mut accounts, &pool.mints[0], &pool.mints[1]).unwrap_err();
+            assert_eq!(err, SolscopeError::InvalidPoolAccount.into());
+        }
+    }
+}
@@ -0,0 +1,444 @@
+// programs/solscope/src/raydium_cpmm.rs
+
+use std::cell::Ref;
+use std::collections::BTreeSet;
+use std::str::FromStr;
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::{AccountMeta, Instruction};
+use bytemuck::{Pod, Zeroable};
+
+use crate::dex::{DexAdapter, SwapMode};
+use crate::errors::SolscopeError;
+
+pub fn raydium_cpmm_program() -> Pubkey {
+    Pubkey::from_str("CPMMoo8L3F4NbTegBCKVNunggL7H1ZpdTHKxQB5qKP1C").unwrap()
+}
+
+/// Seed of the CP-Swap vault / LP mint authority PDA
+pub const CPMM_AUTH_SEED: &[u8] = b"vault_and_lp_mint_auth_seed";
+
+/// Anchor discriminators (sha256("global:<name>")[..8] / sha256("account:PoolState")[..8])
+const SWAP_BASE_INPUT_DISCRIMINATOR: [u8; 8] = [143, 190, 90, 218, 196, 30, 51, 222];
+const SWAP_BASE_OUTPUT_DISCRIMINATOR: [u8; 8] = [55, 217, 98, 86, 163, 74, 180, 173];
+pub const POOL_STATE_DISCRIMINATOR: [u8; 8] = [247, 237, 227, 245, 215, 195, 222, 70];
+
+fn cpmm_swap_data(discriminator: [u8; 8], amount_a: u64, amount_b: u64) -> Vec<u8> {
+    // swap_base_input(amount_in, minimum_amount_out)
+    // swap_base_output(max_amount_in, amount_out)
+    let mut data = Vec::with_capacity(24);
+    data.extend_from_slice(&discriminator);
+    data.extend_from_slice(&amount_a.to_le_bytes());
+    data.extend_from_slice(&amount_b.to_le_bytes());
+    data
+}
+
+/* ======================================================
+ * CP-Swap PoolState (629 bytes after the Anchor discriminator)
+ * ====================================================== */
+pub const CPMM_POOL_STATE_LEN: usize = 629;
+
+/// PoolState.status bit that disables swaps
+pub const CPMM_STATUS_SWAP_DISABLED: u8 = 1 << 2;
+
+#[repr(C, packed)]
+#[derive(Clone, Copy, Pod, Zeroable)]
+pub struct CpmmPoolState {
+    pub amm_config: Pubkey,
+    pub pool_creator: Pubkey,
+    pub token_0_vault: Pubkey,
+    pub token_1_vault: Pubkey,
+    pub lp_mint: Pubkey,
+    pub token_0_mint: Pubkey,
+    pub token_1_mint: Pubkey,
+    pub token_0_program: Pubkey,
+    pub token_1_program: Pubkey,
+    pub observation_key: Pubkey,
+    pub auth_bump: u8,
+    pub status: u8,
+    pub lp_mint_decimals: u8,
+    pub mint_0_decimals: u8,
+    pub mint_1_decimals: u8,
+    pub lp_supply: u64,
+    pub protocol_fees_token_0: u64,
+    pub protocol_fees_token_1: u64,
+    pub fund_fees_token_0: u64,
+    pub fund_fees_token_1: u64,
+    pub open_time: u64,
+    pub recent_epoch: u64,
+    pub padding: [u64; 31],
+}
+
+const _: () = assert!(std::mem::size_of::<CpmmPoolState>() == CPMM_POOL_STATE_LEN);
+
+impl CpmmPoolState {
+    /// Zero-copy view of a pool account that must be owned by the CP-Swap program
+    pub fn load<'a>(pool: &'a AccountInfo, cpmm_program: &Pubkey) -> Result<Ref<'a, Self>> {
+        require!(pool.owner == cpmm_program, SolscopeError::InvalidPoolAccount);
+
+        let data = pool.try_borrow_data()?;
+        require!(
+            data.len() == 8 + CPMM_POOL_STATE_LEN && data[..8] == POOL_STATE_DISCRIMINATOR,
+            SolscopeError::InvalidPoolAccount
+        );
+        Ok(Ref::map(data, |data| bytemuck::from_bytes(&data[8..])))
+    }
+
+    /// Swaps open at open_time and while the swap bit is clear
+    pub fn swap_enabled(&self, unix_timestamp: i64) -> bool {
+        let open_time = self.open_time;
+        self.status & CPMM_STATUS_SWAP_DISABLED == 0
+            && unix_timestamp >= 0
+            && unix_timestamp as u64 >= open_time
+    }
+}
+
+/* ======================================================
+ * CP-Swap swap accounts (DEX_RAYDIUM_CPMM venue layout,
+ * passed through remaining_accounts in this order).
+ * input/output follow the swap direction, as in CP-Swap itself.
+ * ====================================================== */
+pub const RAYDIUM_CPMM_ACCOUNTS_LEN: usize = 11;
+
+#[derive(Accounts)]
+pub struct RaydiumCpmmAccounts<'info> {
+    /// CHECK: Raydium CP-Swap program
+    #[account(address = raydium_cpmm_program())]
+    pub cpmm_program: AccountInfo<'info>,
+
+    /// CHECK: Vault / LP mint authority PDA
+    pub authority: AccountInfo<'info>,
+    /// CHECK: AmmConfig (fee tier)
+    pub amm_config: AccountInfo<'info>,
+    /// CHECK: PoolState
+    #[account(mut)]
+    pub pool_state: AccountInfo<'info>,
+    /// CHECK: Pool vault taking our input
+    #[account(mut)]
+    pub input_vault: AccountInfo<'info>,
+    /// CHECK: Pool vault paying our output
+    #[account(mut)]
+    pub output_vault: AccountInfo<'info>,
+    /// CHECK: Token program of the input mint
+    pub input_token_program: AccountInfo<'info>,
+    /// CHECK: Token program of the output mint
+    pub output_token_program: AccountInfo<'info>,
+    /// CHECK: Input mint
+    pub input_token_mint: AccountInfo<'info>,
+    /// CHECK: Output mint
+    pub output_token_mint: AccountInfo<'info>,
+    /// CHECK: Oracle observation state
+    #[account(mut)]
+    pub observation_state: AccountInfo<'info>,
+}
+
+impl<'info> RaydiumCpmmAccounts<'info> {
+    /// DexAdapter::validate against an explicit clock
+    pub fn validate_at(
+        &self,
+        input_mint: &Pubkey,
+        output_mint: &Pubkey,
+        unix_timestamp: i64,
+    ) -> Result<()> {
+        let pool = CpmmPoolState::load(&self.pool_state, self.cpmm_program.key)?;
+        require!(
+            pool.swap_enabled(unix_timestamp),
+            SolscopeError::PoolNotTradable
+        );
+
+        let authority =
+            Pubkey::create_program_address(&[CPMM_AUTH_SEED, &[pool.auth_bump]], self.cpmm_program.key)
+                .map_err(|_| error!(SolscopeError::InvalidPoolAccount))?;
+        require!(
+            authority == self.authority.key()
+                && pool.amm_config == self.amm_config.key()
+                && pool.observation_key == self.observation_state.key(),
+            SolscopeError::InvalidPoolAccount
+        );
+
+        // pool must pair input with exactly the output mint (either side)
+        let (input, output) = if pool.token_0_mint == *input_mint && pool.token_1_mint == *output_mint
+        {
+            (
+                (pool.token_0_vault, pool.token_0_program),
+                (pool.token_1_vault, pool.token_1_program),
+            )
+        } else if pool.token_1_mint == *input_mint && pool.token_0_mint == *output_mint {
+            (
+                (pool.token_1_vault, pool.token_1_program),
+                (pool.token_0_vault, pool.token_0_program),
+            )
+        } else {
+            return err!(SolscopeError::PoolMintMismatch);
+        };
+
+        require!(
+            self.input_token_mint.key() == *input_mint
+                && self.output_token_mint.key() == *output_mint,
+            SolscopeError::PoolMintMismatch
+        );
+        require!(
+            self.input_vault.key() == input.0
+                && self.input_token_program.key() == input.1
+                && self.output_vault.key() == output.0
+                && self.output_token_program.key() == output.1,
+            SolscopeError::InvalidPoolAccount
+        );
+
+        Ok(())
+    }
+}
+
+impl<'info> DexAdapter<'info> for RaydiumCpmmAccounts<'info> {
+    fn load(accounts: &mut &'info [AccountInfo<'info>]) -> Result<Self> {
+        require!(
+            accounts.len() >= RAYDIUM_CPMM_ACCOUNTS_LEN,
+            SolscopeError::InvalidPoolAccount
+        );
+        RaydiumCpmmAccounts::try_accounts(
+            &crate::ID,
+            accounts,
+            &[],
+            &mut RaydiumCpmmAccountsBumps::default(),
+            &mut BTreeSet::new(),
+        )
+    }
+
+    fn program_id(&self) -> Pubkey {
+        self.cpmm_program.key()
+    }
+
+    fn pool_id(&self) -> Pubkey {
+        self.pool_state.key()
+    }
+
+    fn validate(&mut self, input_mint: &Pubkey, output_mint: &Pubkey) -> Result<()> {
+        self.validate_at(input_mint, output_mint, Clock::get()?.unix_timestamp)
+    }
+
+    /// CP-Swap swap_base_input / swap_base_output (same account layout)
+    fn swap_ix(
+        &self,
+        user_source: &Pubkey,
+        user_dest: &Pubkey,
+        user_owner: &Pubkey,
+        mode: SwapMode,
+        amount_in: u64,
+        min_out: u64,
+    ) -> Result<Instruction> {
+        Ok(Instruction {
+            program_id: self.cpmm_program.key(),
+            accounts: vec![
+                AccountMeta::new_readonly(*user_owner, true),
+                AccountMeta::new_readonly(*self.authority.key, false),
+                AccountMeta::new_readonly(*self.amm_config.key, false),
+                AccountMeta::new(*self.pool_state.key, false),
+                AccountMeta::new(*user_source, false),
+                AccountMeta::new(*user_dest, false),
+                AccountMeta::new(*self.input_vault.key, false),
+                AccountMeta::new(*self.output_vault.key, false),
+                AccountMeta::new_readonly(*self.input_token_program.key, false),
+                AccountMeta::new_readonly(*self.output_token_program.key, false),
+                AccountMeta::new_readonly(*self.input_token_mint.key, false),
+                AccountMeta::new_readonly(*self.output_token_mint.key, false),
+                AccountMeta::new(*self.observation_state.key, false),
+            ],
+            data: match mode {
+                SwapMode::ExactIn => {
+                    cpmm_swap_data(SWAP_BASE_INPUT_DISCRIMINATOR, amount_in, min_out)
+                }
+                SwapMode::ExactOut => {
+                    cpmm_swap_data(SWAP_BASE_OUTPUT_DISCRIMINATOR, amount_in, min_out)
+                }
+            },
+        })
+    }
+
+    fn cpi_accounts(&self) -> Vec<AccountInfo<'info>> {
+        self.to_account_infos()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{put, TestAccount};
+
+    const OPEN_TIME: u64 = 1_720_000_000;
+
+    struct Pool {
+        pool_state: Pubkey,
+        amm_config: Pubkey,
+        observation: Pubkey,
+        mints: [Pubkey; 2],
+        vaults: [Pubkey; 2],
+        programs: [Pubkey; 2],
+    }
+
+    impl Pool {
+        fn new() -> Self {
+            Self {
+                pool_state: Pubkey::new_unique(),
+                amm_config: Pubkey::new_unique(),
+                observation: Pubkey::new_unique(),
+                mints: [Pubkey::new_unique(), Pubkey::new_unique()],
+                vaults: [Pubkey::new_unique(), Pubkey::new_unique()],
+                programs: [Pubkey::new_unique(), Pubkey::new_unique()],
+            }
+        }
+
+        /// PoolState account bytes, fields at their on-chain offsets
+        fn pool_state_data(&self, status: u8) -> Vec<u8> {
+            let (_, auth_bump) =
+                Pubkey::find_program_address(&[CPMM_AUTH_SEED], &raydium_cpmm_program());
+            let mut data = vec![0u8; 8 + CPMM_POOL_STATE_LEN];
+            put(&mut data, 0, &POOL_STATE_DISCRIMINATOR);
+            put(&mut data, 8, self.amm_config.as_ref());
+            put(&mut data, 72, self.vaults[0].as_ref());
+            put(&mut data, 104, self.vaults[1].as_ref());
+            put(&mut data, 168, self.mints[0].as_ref());
+            put(&mut data, 200, self.mints[1].as_ref());
+            put(&mut data, 232, self.programs[0].as_ref());
+            put(&mut data, 264, self.programs[1].as_ref());
+            put(&mut data, 296, self.observation.as_ref());
+            data[328] = auth_bump;
+            data[329] = status;
+            put(&mut data, 373, &OPEN_TIME.to_le_bytes());
+            data
+        }
+
+        /// The 11 venue accounts for a swap of side `input` into the other side
+        fn accounts(&self, input: usize) -> Vec<TestAccount> {
+            let program = raydium_cpmm_program();
+            let (authority, _) = Pubkey::find_program_address(&[CPMM_AUTH_SEED], &program);
+            let output = 1 - input;
+            let none = Pubkey::default();
+            vec![
+                TestAccount::with_key(program, none, vec![]),
+                TestAccount::with_key(authority, none, vec![]),
+                TestAccount::with_key(self.amm_config, program, vec![]),
+                TestAccount::with_key(self.pool_state, program, self.pool_state_data(0)),
+                TestAccount::with_key(self.vaults[input], none, vec![]),
+                TestAccount::with_key(self.vaults[output], none, vec![]),
+                TestAccount::with_key(self.programs[input], none, vec![]),
+                TestAccount::with_key(self.programs[output], none, vec![]),
+                TestAccount::with_key(self.mints[input], none, vec![]),
+                TestAccount::with_key(self.mints[output], none, vec![]),
+                TestAccount::with_key(self.observation, program, vec![]),
+            ]
+        }
+    }
+
+    fn validate(accounts: &mut [TestAccount], input: &Pubkey, output: &Pubkey) -> Result<()> {
+        let infos: Vec<AccountInfo> = accounts.iter_mut().map(TestAccount::info).collect();
+        let mut remaining: &[AccountInfo] = &infos;
+        let venue = RaydiumCpmmAccounts::load(&mut remaining)?;
+        assert!(remaining.is_empty());
+        venue.validate_at(input, output, OPEN_TIME as i64)
+    }
+
+    #[test]
+    fn pool_state_decodes_at_on_chain_offsets() {
+        let pool = Pool::new();
+        let mut account = TestAccount::with_key(
+            pool.pool_state,
+            raydium_cpmm_program(),
+            pool.pool_state_data(CPMM_STATUS_SWAP_DISABLED),
+        );
+        let info = account.info();
+        let state = CpmmPoolState::load(&info, &raydium_cpmm_program()).unwrap();
+
+        assert_eq!({ state.amm_config }, pool.amm_config);
+        assert_eq!({ state.token_0_vault }, pool.vaults[0]);
+        assert_eq!({ state.token_1_vault }, pool.vaults[1]);
+        assert_eq!({ state.token_0_mint }, pool.mints[0]);
+        assert_eq!({ state.token_1_mint }, pool.mints[1]);
+        assert_eq!({ state.token_0_program }, pool.programs[0]);
+        assert_eq!({ state.token_1_program }, pool.programs[1]);
+        assert_eq!({ state.observation_key }, pool.observation);
+        assert_eq!({ state.status }, CPMM_STATUS_SWAP_DISABLED);
+        assert_eq!({ state.open_time }, OPEN_TIME);
+    }
+
+    #[test]
+    fn pool_state_rejects_wrong_owner_or_discriminator() {
+        let pool = Pool::new();
+        let mut account = TestAccount::with_key(
+            pool.pool_state,
+            Pubkey::new_unique(),
+            pool.pool_state_data(0),
+        );
+        assert!(CpmmPoolState::load(&account.info(), &raydium_cpmm_program()).is_err());
+
+        account.owner = raydium_cpmm_program();
+        account.data[0] ^= 1;
+        assert!(CpmmPoolState::load(&account.info(), &raydium_cpmm_program()).is_err());
+    }
+
+    #[test]
+    fn swap_enabled_from_open_time_while_swap_bit_clear() {
+        let pool = Pool::new();
+        let data = pool.pool_state_data(0);
+        let state: &CpmmPoolState = bytemuck::from_bytes(&data[8..]);
+        let open = OPEN_TIME as i64;
+        assert!(!state.swap_enabled(open - 1));
+        assert!(state.swap_enabled(open));
+        assert!(!state.swap_enabled(-1));
+
+        let data = pool.pool_state_data(CPMM_STATUS_SWAP_DISABLED);
+        let state: &CpmmPoolState = bytemuck::from_bytes(&data[8..]);
+        assert!(!state.swap_enabled(open + 1));
+    }
+
+    #[test]
+    fn valid_pool_swaps_in_both_directions() {
+        let pool = Pool::new();
+        validate(&mut pool.accounts(0), &pool.mints[0], &pool.mints[1]).unwrap();
+        validate(&mut pool.accounts(1), &pool.mints[1], &pool.mints[0]).unwrap();
+    }
+
+    #[test]
+    fn rejects_unrelated_mints_and_disabled_pools() {
+        let pool = Pool::new();
+
+        let err =
+            validate(&mut pool.accounts(0), &pool.mints[0], &Pubkey::new_unique()).unwrap_err();
+        assert_eq!(err, SolscopeError::PoolMintMismatch.into());
+
+        // vaults passed for 0 -> 1 while the swap claims 1 -> 0
+        let err = validate(&mut pool.accounts(0), &pool.mints[1], &pool.mints[0]).unwrap_err();
+        assert_eq!(err, SolscopeError::PoolMintMismatch.into());
+
+        let mut accounts = pool.accounts(0);
+        accounts[3].data = pool.pool_state_data(CPMM_STATUS_SWAP_DISABLED);
+        let err = validate(&mut accounts, &pool.mints[0], &pool.mints[1]).unwrap_err();
+        assert_eq!(err, SolscopeError::PoolNotTradable.into());
+    }
+
+    #[test]
+    fn rejects_accounts_that_do_not_match_the_pool() {
+        let pool = Pool::new();
+
+        // authority / amm config / vaults / token programs / observation
+        for index in [1, 2, 4, 5, 6, 7, 10] {
+            let mut accounts = pool.accounts(0);
+            accounts[index].key = Pubkey::new_unique();
+            let err = validate(&mut accounts, &pool.mints[0], &pool.mints[1]).unwrap_err();
+            assert_eq!(
+                err,
+                SolscopeError::InvalidPoolAccount.into(),
+                "account {index}"
+            );
+        }
+
+        // both vaults swapped for each other
+        let mut accounts = pool.accounts(0);
+        accounts.swap(4, 5);
+        let err = validate(&mut accounts, &pool.mints[0], &pool.mints[1]).unwrap_err();
+        assert_eq!(err, SolscopeError::InvalidPoolAccount.into());
+
+        let mut accounts = pool.accounts(0);
+        accounts[0].key = Pubkey::new_unique();
+        assert!(validate(&mut accounts, &pool.mints[0], &pool.mints[1]).is_err());
+    }
+}
@@ -2,7 +2,7 @@
 
 use anchor_lang::prelude::*;
 
-use crate::state::BotMeta;
+use crate::state::{BotMeta, BotStatus};
 
 #[derive(Accounts)]
 #[instruction(bot_id_hash: [u8; 32])]
@@ -50,7 +50,16 @@ pub fn handler(ctx: Context<RegisterBot>, bot_id_hash: [u8; 32]) -> Result<()> {
     bot_meta.vault = ctx.accounts.vault.key();
     bot_meta.created_at = Clock::get()?.unix_timestamp;
     bot_meta.bump = ctx.bumps.bot_meta;
-    bot_meta.paused = false;
+    bot_meta.pause_flags = 0;
+    bot_meta.status = BotStatus::Active;
+    bot_meta.operator = None;
+    bot_meta.recovery = None;
+    bot_meta.risk_config = None;
+    bot_meta.mint_policy = None;
+    bot_meta.pool_allowlist = None;
+    bot_meta.oracle_config = None;
+    bot_meta.session_keys = 0;
+    bot_meta.open_orders_record = None;
 
     Ok(())
 }
@@ -0,0 +1,37 @@
+// programs/solscope/src/revoke_session_key.rs
+
+use anchor_lang::prelude::*;
+
+use crate::state::{BotMeta, SessionKey};
+
+#[derive(Accounts)]
+#[instruction(bot_id_hash: [u8; 32])]
+pub struct RevokeSessionKey<'info> {
+    /// Bot owner (receives the SessionKey rent back)
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"bot", owner.key().as_ref(), &bot_id_hash],
+        bump,
+        has_one = owner,
+    )]
+    pub bot_meta: Account<'info, BotMeta>,
+
+    #[account(
+        mut,
+        close = owner,
+        seeds = [b"session", bot_meta.key().as_ref(), session_key.key.as_ref()],
+        bump = session_key.bump,
+        has_one = bot_meta,
+    )]
+    pub session_key: Account<'info, SessionKey>,
+}
+
+pub fn handler(ctx: Context<RevokeSessionKey>, _bot_id_hash: [u8; 32]) -> Result<()> {
+    // owner-only enforced by has_one + owner signer; Anchor closes the PDA
+    let bot_meta = &mut ctx.accounts.bot_meta;
+    bot_meta.session_keys = bot_meta.session_keys.saturating_sub(1);
+    Ok(())
+}
@@ -0,0 +1,33 @@
+// programs/solscope/src/set_operator.rs
+
+use anchor_lang::prelude::*;
+
+use crate::errors::SolscopeError;
+use crate::state::BotMeta;
+
+#[derive(Accounts)]
+#[instruction(bot_id_hash: [u8; 32])]
+pub struct SetOperator<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"bot", owner.key().as_ref(), &bot_id_hash],
+        bump,
+        has_one = owner,
+    )]
+    pub bot_meta: Account<'info, BotMeta>,
+}
+
+pub fn handler(ctx: Context<SetOperator>, _bot_id_hash: [u8; 32], operator: Pubkey) -> Result<()> {
+    // operator must be a distinct hot key, never the owner itself
+    require!(
+        operator != Pubkey::default() && operator != ctx.accounts.owner.key(),
+        SolscopeError::Unauthorized
+    );
+
+    // owner-only enforced by has_one + owner signer
+    ctx.accounts.bot_meta.operator = Some(operator);
+    Ok(())
+}
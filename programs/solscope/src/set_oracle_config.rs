@@ -0,0 +1,70 @@
+// programs/solscope/src/set_oracle_config.rs
+
+use anchor_lang::prelude::*;
+use anchor_spl::token::Mint;
+
+use crate::errors::SolscopeError;
+use crate::pyth::PythPriceHeader;
+use crate::state::{BotMeta, OracleConfig};
+
+#[derive(Accounts)]
+#[instruction(bot_id_hash: [u8; 32])]
+pub struct SetOracleConfig<'info> {
+    /// Bot owner (pays for the OracleConfig PDA on first use)
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"bot", owner.key().as_ref(), &bot_id_hash],
+        bump,
+        has_one = owner,
+    )]
+    pub bot_meta: Account<'info, BotMeta>,
+
+    /// OracleConfig PDA (1 per bot)
+    #[account(
+        init_if_needed,
+        payer = owner,
+        space = 8 + OracleConfig::LEN,
+        seeds = [b"oracle", bot_meta.key().as_ref()],
+        bump
+    )]
+    pub oracle_config: Account<'info, OracleConfig>,
+
+    /// CHECK: Pyth price account (owner and layout checked in handler)
+    pub oracle: AccountInfo<'info>,
+
+    /// Token mint `oracle` quotes in SOL
+    pub mint: Account<'info, Mint>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(
+    ctx: Context<SetOracleConfig>,
+    _bot_id_hash: [u8; 32],
+    max_deviation_bps: u16,
+    max_staleness_secs: u32,
+) -> Result<()> {
+    // owner-only enforced by has_one + owner signer
+    require!(
+        max_deviation_bps > 0 && u128::from(max_deviation_bps) < OracleConfig::BPS_DENOMINATOR,
+        SolscopeError::InvalidAmount
+    );
+    require!(max_staleness_secs > 0, SolscopeError::InvalidAmount);
+    PythPriceHeader::load(&ctx.accounts.oracle)?;
+
+    let oracle_config = &mut ctx.accounts.oracle_config;
+
+    oracle_config.bot_meta = ctx.accounts.bot_meta.key();
+    oracle_config.oracle = ctx.accounts.oracle.key();
+    oracle_config.mint = ctx.accounts.mint.key();
+    oracle_config.max_deviation_bps = max_deviation_bps;
+    oracle_config.max_staleness_secs = max_staleness_secs;
+    oracle_config.bump = ctx.bumps.oracle_config;
+
+    // from now on execute_trade must pass this account and its oracle
+    ctx.accounts.bot_meta.oracle_config = Some(oracle_config.key());
+    Ok(())
+}
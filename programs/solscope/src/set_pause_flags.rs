@@ -0,0 +1,29 @@
+// programs/solscope/src/set_pause_flags.rs
+
+use anchor_lang::prelude::*;
+
+use crate::errors::SolscopeError;
+use crate::state::{BotMeta, PAUSE_ALL};
+
+#[derive(Accounts)]
+#[instruction(bot_id_hash: [u8; 32])]
+pub struct SetPauseFlags<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"bot", owner.key().as_ref(), &bot_id_hash],
+        bump,
+        has_one = owner,
+    )]
+    pub bot_meta: Account<'info, BotMeta>,
+}
+
+pub fn handler(ctx: Context<SetPauseFlags>, _bot_id_hash: [u8; 32], pause_flags: u8) -> Result<()> {
+    require!(pause_flags & !PAUSE_ALL == 0, SolscopeError::InvalidPauseFlags);
+
+    // owner-only enforced by has_one + owner signer
+    ctx.accounts.bot_meta.pause_flags = pause_flags;
+    Ok(())
+}
@@ -1,7 +1,9 @@
 // programs/solscope/src/set_paused.rs
 
 use anchor_lang::prelude::*;
-use crate::state::BotMeta;
+
+use crate::errors::SolscopeError;
+use crate::state::{BotMeta, BotStatus};
 
 #[derive(Accounts)]
 #[instruction(bot_id_hash: [u8; 32])]
@@ -20,7 +22,18 @@ pub struct SetPaused<'info> {
 
 pub fn handler(ctx: Context<SetPaused>, _bot_id_hash: [u8; 32], paused: bool) -> Result<()> {
     // owner-only enforced by has_one + owner signer
-    ctx.accounts.bot_meta.paused = paused;
+    let next = if paused {
+        BotStatus::Paused
+    } else {
+        BotStatus::Active
+    };
+
+    let bot_meta = &mut ctx.accounts.bot_meta;
+    require!(
+        bot_meta.status.can_transition_to(next),
+        SolscopeError::InvalidStatusTransition
+    );
+    bot_meta.status = next;
     Ok(())
 }
 
@@ -0,0 +1,29 @@
+// programs/solscope/src/set_recovery.rs
+
+use anchor_lang::prelude::*;
+use crate::state::BotMeta;
+
+#[derive(Accounts)]
+#[instruction(bot_id_hash: [u8; 32])]
+pub struct SetRecovery<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"bot", owner.key().as_ref(), &bot_id_hash],
+        bump,
+        has_one = owner,
+    )]
+    pub bot_meta: Account<'info, BotMeta>,
+}
+
+pub fn handler(
+    ctx: Context<SetRecovery>,
+    _bot_id_hash: [u8; 32],
+    recovery: Option<Pubkey>,
+) -> Result<()> {
+    // owner-only enforced by has_one + owner signer; None clears it
+    ctx.accounts.bot_meta.recovery = recovery;
+    Ok(())
+}
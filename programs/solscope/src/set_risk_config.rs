@@ -0,0 +1,59 @@
+// programs/solscope/src/set_risk_config.rs
+
+use anchor_lang::prelude::*;
+
+use crate::state::{BotMeta, RiskConfig};
+
+#[derive(Accounts)]
+#[instruction(bot_id_hash: [u8; 32])]
+pub struct SetRiskConfig<'info> {
+    /// Bot owner (pays for the RiskConfig PDA on first use)
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"bot", owner.key().as_ref(), &bot_id_hash],
+        bump,
+        has_one = owner,
+    )]
+    pub bot_meta: Account<'info, BotMeta>,
+
+    /// RiskConfig PDA (1 per bot)
+    #[account(
+        init_if_needed,
+        payer = owner,
+        space = 8 + RiskConfig::LEN,
+        seeds = [b"risk", bot_meta.key().as_ref()],
+        bump
+    )]
+    pub risk_config: Account<'info, RiskConfig>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(
+    ctx: Context<SetRiskConfig>,
+    _bot_id_hash: [u8; 32],
+    max_buy_lamports: u64,
+    max_sell_amount: u64,
+    sell_limit_mint: Option<Pubkey>,
+    max_daily_notional: u64,
+    max_trades_per_day: u32,
+) -> Result<()> {
+    // owner-only enforced by has_one + owner signer; 0 = unlimited
+    let risk_config = &mut ctx.accounts.risk_config;
+
+    risk_config.bot_meta = ctx.accounts.bot_meta.key();
+    risk_config.max_buy_lamports = max_buy_lamports;
+    risk_config.max_sell_amount = max_sell_amount;
+    risk_config.sell_limit_mint = sell_limit_mint;
+    risk_config.max_daily_notional = max_daily_notional;
+    risk_config.max_trades_per_day = max_trades_per_day;
+    risk_config.bump = ctx.bumps.risk_config;
+    // day counters are kept across updates so limits can't be reset mid-day
+
+    // from now on execute_trade must pass this account
+    ctx.accounts.bot_meta.risk_config = Some(risk_config.key());
+    Ok(())
+}
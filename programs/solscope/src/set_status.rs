@@ -0,0 +1,33 @@
+// programs/solscope/src/set_status.rs
+
+use anchor_lang::prelude::*;
+
+use crate::errors::SolscopeError;
+use crate::state::{BotMeta, BotStatus};
+
+#[derive(Accounts)]
+#[instruction(bot_id_hash: [u8; 32])]
+pub struct SetStatus<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"bot", owner.key().as_ref(), &bot_id_hash],
+        bump,
+        has_one = owner,
+    )]
+    pub bot_meta: Account<'info, BotMeta>,
+}
+
+pub fn handler(ctx: Context<SetStatus>, _bot_id_hash: [u8; 32], status: BotStatus) -> Result<()> {
+    // owner-only enforced by has_one + owner signer
+    let bot_meta = &mut ctx.accounts.bot_meta;
+    require!(
+        bot_meta.status.can_transition_to(status) && status != BotStatus::Closed,
+        SolscopeError::InvalidStatusTransition
+    );
+
+    bot_meta.status = status;
+    Ok(())
+}
@@ -0,0 +1,167 @@
+// programs/solscope/src/settle_funds.rs
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program::invoke_signed;
+
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::{Mint, Token, TokenAccount},
+};
+
+use crate::{
+    errors::SolscopeError,
+    guards,
+    openbook_v2::{self, openbook_v2_program, MarketHeader},
+    state::{BotAction, BotMeta, OpenOrdersRecord, SessionKey},
+};
+
+#[derive(Accounts)]
+#[instruction(bot_id_hash: [u8; 32])]
+pub struct SettleFunds<'info> {
+    /// Owner, delegated operator or live session key (pays ATA rent and any OpenBook penalty)
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// BotMeta PDA (derived from the stored owner, not the signer)
+    #[account(
+        seeds = [b"bot", bot_meta.owner.as_ref(), &bot_id_hash],
+        bump,
+        has_one = vault
+    )]
+    pub bot_meta: Account<'info, BotMeta>,
+
+    /// CHECK: Vault PDA (open-orders owner, receives the settled tokens)
+    #[account(
+        mut,
+        seeds = [b"vault", bot_meta.owner.as_ref(), &bot_id_hash],
+        bump
+    )]
+    pub vault: AccountInfo<'info>,
+
+    /// SessionKey PDA (required only when authority is a session key)
+    #[account(
+        mut,
+        seeds = [b"session", bot_meta.key().as_ref(), authority.key().as_ref()],
+        bump = session_key.bump,
+        has_one = bot_meta,
+    )]
+    pub session_key: Option<Account<'info, SessionKey>>,
+
+    /// OpenOrdersRecord PDA
+    #[account(
+        seeds = [b"open_orders", bot_meta.key().as_ref()],
+        bump = open_orders_record.bump,
+        has_one = bot_meta,
+    )]
+    pub open_orders_record: Account<'info, OpenOrdersRecord>,
+
+    /* ========== OpenBook v2 Accounts ========== */
+    /// CHECK: OpenBook v2 market (owner + discriminator checked in handler)
+    #[account(mut)]
+    pub market: AccountInfo<'info>,
+
+    /// CHECK: Vault's open-orders account recorded for `market`
+    #[account(
+        mut,
+        constraint = open_orders_record.open_orders_for(market.key)
+            == Some(open_orders_account.key()) @ SolscopeError::OpenOrdersNotFound
+    )]
+    pub open_orders_account: AccountInfo<'info>,
+
+    /// CHECK: Market authority PDA (checked against the market)
+    pub market_authority: AccountInfo<'info>,
+    /// CHECK: Market base vault (checked against the market)
+    #[account(mut)]
+    pub market_base_vault: AccountInfo<'info>,
+    /// CHECK: Market quote vault (checked against the market)
+    #[account(mut)]
+    pub market_quote_vault: AccountInfo<'info>,
+
+    pub base_mint: Account<'info, Mint>,
+    pub quote_mint: Account<'info, Mint>,
+
+    /// Vault ATA receiving settled base
+    #[account(
+        init_if_needed,
+        payer = authority,
+        associated_token::mint = base_mint,
+        associated_token::authority = vault
+    )]
+    pub vault_base_ata: Account<'info, TokenAccount>,
+
+    /// Vault ATA receiving settled quote
+    #[account(
+        init_if_needed,
+        payer = authority,
+        associated_token::mint = quote_mint,
+        associated_token::authority = vault
+    )]
+    pub vault_quote_ata: Account<'info, TokenAccount>,
+
+    /// CHECK: OpenBook v2 program
+    #[account(address = openbook_v2_program())]
+    pub openbook_program: AccountInfo<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Move filled proceeds and released funds from the open-orders account back to the vault ATAs
+pub fn handler(ctx: Context<SettleFunds>, bot_id_hash: [u8; 32]) -> Result<()> {
+    require!(
+        ctx.accounts.bot_meta.bot_id_hash == bot_id_hash,
+        SolscopeError::BotIdMismatch
+    );
+    // settling only returns funds, so it stays open in every status but Closed
+    ctx.accounts.bot_meta.require_allows(BotAction::Settle)?;
+
+    /* ================= Signer authorization ================= */
+    guards::authorize_trader(
+        &ctx.accounts.bot_meta,
+        ctx.accounts.authority.key,
+        ctx.accounts.session_key.as_mut(),
+        None,
+    )?;
+
+    /* ================= Market validation ================= */
+    let program = ctx.accounts.openbook_program.key();
+    let market_key = ctx.accounts.market.key();
+    let market = *MarketHeader::load(&ctx.accounts.market, &program)?;
+    require!(
+        market.base_mint == ctx.accounts.base_mint.key()
+            && market.quote_mint == ctx.accounts.quote_mint.key(),
+        SolscopeError::PoolMintMismatch
+    );
+    require!(
+        market.market_authority == ctx.accounts.market_authority.key()
+            && market.market_base_vault == ctx.accounts.market_base_vault.key()
+            && market.market_quote_vault == ctx.accounts.market_quote_vault.key(),
+        SolscopeError::InvalidPoolAccount
+    );
+
+    /* ================= PDA signer ================= */
+    let owner = ctx.accounts.bot_meta.owner;
+    let vault_seeds: &[&[u8]] = &[
+        b"vault",
+        owner.as_ref(),
+        &bot_id_hash,
+        &[ctx.bumps.vault],
+    ];
+    let signer_seeds = &[vault_seeds];
+
+    /* ================= OpenBook settle_funds ================= */
+    let ix = openbook_v2::settle_funds_ix(
+        &program,
+        &market_key,
+        &market,
+        ctx.accounts.vault.key,
+        ctx.accounts.authority.key,
+        ctx.accounts.open_orders_account.key,
+        &ctx.accounts.vault_base_ata.key(),
+        &ctx.accounts.vault_quote_ata.key(),
+    );
+
+    invoke_signed(&ix, &ctx.accounts.to_account_infos(), signer_seeds)?;
+    Ok(())
+}
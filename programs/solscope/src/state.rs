@@ -2,8 +2,99 @@
 
 use anchor_lang::prelude::*;
 
+use crate::errors::SolscopeError;
+
+/* ======================================================
+ * Pause Flags (BotMeta.pause_flags bits)
+ * ====================================================== */
+/// Blocks every trade (and the assert_vault gate)
+pub const PAUSE_TRADING: u8 = 1 << 0;
+/// Blocks fund_vault / fund_vault_token
+pub const PAUSE_FUNDING: u8 = 1 << 1;
+/// Blocks withdraw / withdraw_all / withdraw_token
+pub const PAUSE_WITHDRAWALS: u8 = 1 << 2;
+/// Blocks BUYs only; SELLs may still exit positions
+pub const PAUSE_NEW_POSITIONS: u8 = 1 << 3;
+
+pub const PAUSE_ALL: u8 =
+    PAUSE_TRADING | PAUSE_FUNDING | PAUSE_WITHDRAWALS | PAUSE_NEW_POSITIONS;
+
+/* ======================================================
+ * Bot Lifecycle
+ * ====================================================== */
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum BotStatus {
+    /// Normal operation (subject to pause flags)
+    Active,
+    /// Everything frozen except emergency_withdraw / close / OpenBook unwinding
+    Paused,
+    /// Unwinding: SELLs, withdrawals and OpenBook unwinding only
+    Liquidating,
+    /// Retiring: withdrawals, close and OpenBook unwinding only
+    Closing,
+    /// Terminal
+    Closed,
+}
+
+/// Handler-level actions gated by BotStatus + pause flags
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum BotAction {
+    Assert,
+    Fund,
+    Withdraw,
+    Buy,
+    Sell,
+    EmergencyWithdraw,
+    Close,
+    /// Unwind OpenBook state (cancel / settle / close open orders)
+    Settle,
+}
+
+impl BotStatus {
+    /// Allowed-state table: which actions each status permits
+    pub fn check(self, action: BotAction) -> Result<()> {
+        use BotAction::*;
+
+        let allowed = match self {
+            BotStatus::Active => matches!(action, Assert | Fund | Withdraw | Buy | Sell | Settle),
+            BotStatus::Paused => matches!(action, EmergencyWithdraw | Close | Settle),
+            BotStatus::Liquidating => matches!(action, Assert | Withdraw | Sell | Settle),
+            BotStatus::Closing => matches!(action, Withdraw | Close | Settle),
+            BotStatus::Closed => false,
+        };
+        if allowed {
+            return Ok(());
+        }
+
+        let err = match self {
+            // emergency/close paths require the bot to be stopped first
+            BotStatus::Active => SolscopeError::BotNotPaused,
+            BotStatus::Paused => SolscopeError::BotPaused,
+            BotStatus::Liquidating => SolscopeError::BotLiquidating,
+            BotStatus::Closing => SolscopeError::BotClosing,
+            BotStatus::Closed => SolscopeError::BotClosed,
+        };
+        Err(err.into())
+    }
+
+    /// Enforced transitions (Closed is only reachable through close_bot)
+    pub fn can_transition_to(self, next: BotStatus) -> bool {
+        use BotStatus::*;
+
+        matches!(
+            (self, next),
+            (Active, Paused | Liquidating | Closing)
+                | (Paused, Active | Liquidating | Closing)
+                | (Liquidating, Active | Paused | Closing)
+                | (Closing, Closed)
+        )
+    }
+}
+
 /* ======================================================
  * Bot Metadata (1 per bot)
+ * New fields are appended only, and must default to zero
+ * bytes: migrate_bot_meta grows old accounts by zero-fill.
  * ====================================================== */
 #[account]
 pub struct BotMeta {
@@ -22,8 +113,35 @@ pub struct BotMeta {
     /// PDA bump for BotMeta
     pub bump: u8,
 
-    /// Emergency pause flag
-    pub paused: bool,
+    /// Emergency pause bitflags (PAUSE_*)
+    pub pause_flags: u8,
+
+    /// Lifecycle status
+    pub status: BotStatus,
+
+    /// Optional hot key allowed to trade (never withdraw or reconfigure)
+    pub operator: Option<Pubkey>,
+
+    /// Optional pre-registered destination for emergency withdrawals
+    pub recovery: Option<Pubkey>,
+
+    /// RiskConfig PDA once the owner has set limits (must then be passed to trades)
+    pub risk_config: Option<Pubkey>,
+
+    /// MintPolicy PDA once created (must then be passed to trades)
+    pub mint_policy: Option<Pubkey>,
+
+    /// PoolAllowlist PDA once created (must then be passed to trades)
+    pub pool_allowlist: Option<Pubkey>,
+
+    /// OracleConfig PDA once configured (must then be passed to execute_trade)
+    pub oracle_config: Option<Pubkey>,
+
+    /// Live SessionKey PDAs (close_bot must close them all)
+    pub session_keys: u16,
+
+    /// OpenOrdersRecord PDA once created (close_bot requires it empty)
+    pub open_orders_record: Option<Pubkey>,
 }
 
 impl BotMeta {
@@ -33,12 +151,420 @@ impl BotMeta {
     /// vault Pubkey (32)
     /// created_at i64 (8)
     /// bump u8 (1)
-    /// paused bool (1)
+    /// pause_flags u8 (1)
+    /// status BotStatus (1)
+    /// operator Option<Pubkey> (1 + 32)
+    /// recovery Option<Pubkey> (1 + 32)
+    /// risk_config Option<Pubkey> (1 + 32)
+    /// mint_policy Option<Pubkey> (1 + 32)
+    /// pool_allowlist Option<Pubkey> (1 + 32)
+    /// oracle_config Option<Pubkey> (1 + 32)
+    /// session_keys u16 (2)
+    /// open_orders_record Option<Pubkey> (1 + 32)
     pub const LEN: usize =
         32 + // owner
         32 + // bot_id_hash
         32 + // vault
         8  + // created_at
         1  + // bump
-        1;   // paused
+        1  + // pause_flags
+        1  + // status
+        33 + // operator
+        33 + // recovery
+        33 + // risk_config
+        33 + // mint_policy
+        33 + // pool_allowlist
+        33 + // oracle_config
+        2  + // session_keys
+        33;  // open_orders_record
+
+    /// True if any of the given PAUSE_* bits is set
+    pub fn is_paused(&self, flags: u8) -> bool {
+        self.pause_flags & flags != 0
+    }
+
+    /// Lifecycle table first, then the granular pause flags
+    pub fn require_allows(&self, action: BotAction) -> Result<()> {
+        self.status.check(action)?;
+
+        match action {
+            BotAction::Assert | BotAction::Sell => {
+                require!(!self.is_paused(PAUSE_TRADING), SolscopeError::BotPaused);
+            }
+            BotAction::Buy => {
+                require!(!self.is_paused(PAUSE_TRADING), SolscopeError::BotPaused);
+                require!(
+                    !self.is_paused(PAUSE_NEW_POSITIONS),
+                    SolscopeError::NewPositionsPaused
+                );
+            }
+            BotAction::Fund => {
+                require!(!self.is_paused(PAUSE_FUNDING), SolscopeError::FundingPaused);
+            }
+            BotAction::Withdraw => {
+                require!(
+                    !self.is_paused(PAUSE_WITHDRAWALS),
+                    SolscopeError::WithdrawalsPaused
+                );
+            }
+            // unwinding only returns funds, so no pause flag blocks it
+            BotAction::EmergencyWithdraw | BotAction::Close | BotAction::Settle => {}
+        }
+        Ok(())
+    }
+
+    /// Owner or the delegated operator may sign trades
+    pub fn can_trade(&self, signer: &Pubkey) -> bool {
+        self.owner == *signer || self.operator == Some(*signer)
+    }
+
+    /// Emergency funds may only go to the owner or the recovery address
+    pub fn is_recovery_destination(&self, destination: &Pubkey) -> bool {
+        self.owner == *destination || self.recovery == Some(*destination)
+    }
+}
+
+/* ======================================================
+ * Session Key (short-lived trade signer, 1 per key per bot)
+ * ====================================================== */
+#[account]
+pub struct SessionKey {
+    /// BotMeta this session key trades for
+    pub bot_meta: Pubkey,
+
+    /// Hot key allowed to sign execute_trade
+    pub key: Pubkey,
+
+    /// Unix timestamp (seconds) after which the key is dead
+    pub expires_at: i64,
+
+    /// Max lamports a single BUY may spend
+    pub max_lamports_per_trade: u64,
+
+    /// Lamports left to spend across all BUYs
+    pub remaining_budget: u64,
+
+    /// PDA bump for SessionKey
+    pub bump: u8,
+}
+
+impl SessionKey {
+    pub const LEN: usize =
+        32 + // bot_meta
+        32 + // key
+        8  + // expires_at
+        8  + // max_lamports_per_trade
+        8  + // remaining_budget
+        1;   // bump
+}
+
+/* ======================================================
+ * Risk Config (1 per bot, optional)
+ * ====================================================== */
+#[account]
+pub struct RiskConfig {
+    /// BotMeta these limits apply to
+    pub bot_meta: Pubkey,
+
+    /// Max lamports a single BUY may spend (0 = unlimited)
+    pub max_buy_lamports: u64,
+
+    /// Max token amount a single SELL may spend (0 = unlimited)
+    pub max_sell_amount: u64,
+
+    /// Mint max_sell_amount is denominated in (None = applies to every token spend)
+    pub sell_limit_mint: Option<Pubkey>,
+
+    /// Max lamports traded per UTC day, BUY spend + SELL proceeds (0 = unlimited)
+    pub max_daily_notional: u64,
+
+    /// Max trades per UTC day (0 = unlimited)
+    pub max_trades_per_day: u32,
+
+    /// UTC day index (unix_timestamp / 86400) the counters below belong to
+    pub current_day: i64,
+
+    /// Lamports traded so far in current_day
+    pub day_notional: u64,
+
+    /// Trades executed so far in current_day
+    pub day_trades: u32,
+
+    /// PDA bump for RiskConfig
+    pub bump: u8,
+}
+
+impl RiskConfig {
+    pub const LEN: usize =
+        32 + // bot_meta
+        8  + // max_buy_lamports
+        8  + // max_sell_amount
+        33 + // sell_limit_mint
+        8  + // max_daily_notional
+        4  + // max_trades_per_day
+        8  + // current_day
+        8  + // day_notional
+        4  + // day_trades
+        1;   // bump
+
+    pub const SECONDS_PER_DAY: i64 = 86_400;
+
+    /// Reset counters when the UTC day has rolled over
+    pub fn roll_day(&mut self, unix_timestamp: i64) {
+        let day = unix_timestamp.div_euclid(Self::SECONDS_PER_DAY);
+        if day != self.current_day {
+            self.current_day = day;
+            self.day_notional = 0;
+            self.day_trades = 0;
+        }
+    }
+
+    /// Per-trade size + trade count gates (before any funds move).
+    /// `spent_mint` is the token a SELL spends; the token limit only applies to sell_limit_mint.
+    pub fn check_trade(&self, is_buy: bool, spent_mint: &Pubkey, amount_in: u64) -> Result<()> {
+        if is_buy {
+            require!(
+                self.max_buy_lamports == 0 || amount_in <= self.max_buy_lamports,
+                SolscopeError::RiskBuyLimitExceeded
+            );
+        } else if self.sell_limit_mint.unwrap_or(*spent_mint) == *spent_mint {
+            require!(
+                self.max_sell_amount == 0 || amount_in <= self.max_sell_amount,
+                SolscopeError::RiskSellLimitExceeded
+            );
+        }
+        require!(
+            self.max_trades_per_day == 0 || self.day_trades < self.max_trades_per_day,
+            SolscopeError::RiskDailyTradesExceeded
+        );
+        Ok(())
+    }
+
+    /// Trades with no SOL leg can't be counted in lamports; refuse them under a daily cap
+    pub fn check_unpriced_trade(&self) -> Result<()> {
+        require!(
+            self.max_daily_notional == 0,
+            SolscopeError::RiskNotionalUnpriced
+        );
+        Ok(())
+    }
+
+    /// Consume one trade + its lamport notional from today's allowance
+    pub fn record_trade(&mut self, notional: u64) -> Result<()> {
+        let day_notional = self
+            .day_notional
+            .checked_add(notional)
+            .ok_or(SolscopeError::RiskDailyNotionalExceeded)?;
+        require!(
+            self.max_daily_notional == 0 || day_notional <= self.max_daily_notional,
+            SolscopeError::RiskDailyNotionalExceeded
+        );
+
+        self.day_notional = day_notional;
+        self.day_trades += 1;
+        Ok(())
+    }
+}
+
+/* ======================================================
+ * Mint Policy (1 per bot, optional)
+ * ====================================================== */
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum MintPolicyMode {
+    /// Only listed mints may be traded
+    Allowlist,
+    /// Listed mints may never be traded
+    Denylist,
+}
+
+#[account]
+pub struct MintPolicy {
+    /// BotMeta this policy applies to
+    pub bot_meta: Pubkey,
+
+    /// Allowlist or denylist semantics for `mints`
+    pub mode: MintPolicyMode,
+
+    /// Listed mints (max MAX_MINTS)
+    pub mints: Vec<Pubkey>,
+
+    /// PDA bump for MintPolicy
+    pub bump: u8,
+}
+
+impl MintPolicy {
+    pub const MAX_MINTS: usize = 300;
+
+    pub const LEN: usize =
+        32 + // bot_meta
+        1  + // mode
+        4 + 32 * Self::MAX_MINTS + // mints
+        1;   // bump
+
+    pub fn allows(&self, mint: &Pubkey) -> bool {
+        let listed = self.mints.contains(mint);
+        match self.mode {
+            MintPolicyMode::Allowlist => listed,
+            MintPolicyMode::Denylist => !listed,
+        }
+    }
+}
+
+/* ======================================================
+ * Pool Allowlist (1 per bot, optional)
+ * ====================================================== */
+#[account]
+pub struct PoolAllowlist {
+    /// BotMeta this allowlist applies to
+    pub bot_meta: Pubkey,
+
+    /// Raydium AMM ids the bot may route through (max MAX_POOLS)
+    pub amms: Vec<Pubkey>,
+
+    /// PDA bump for PoolAllowlist
+    pub bump: u8,
+}
+
+impl PoolAllowlist {
+    pub const MAX_POOLS: usize = 64;
+
+    pub const LEN: usize =
+        32 + // bot_meta
+        4 + 32 * Self::MAX_POOLS + // amms
+        1;   // bump
+}
+
+/* ======================================================
+ * Oracle Config (1 per bot, optional)
+ * ====================================================== */
+#[account]
+pub struct OracleConfig {
+    /// BotMeta this price band applies to
+    pub bot_meta: Pubkey,
+
+    /// Pyth-layout price account quoting `mint` in SOL
+    pub oracle: Pubkey,
+
+    /// Token mint the oracle prices (fills of any other mint are rejected)
+    pub mint: Pubkey,
+
+    /// Max adverse deviation of the fill price from the oracle (bps)
+    pub max_deviation_bps: u16,
+
+    /// Max age of the oracle price (seconds)
+    pub max_staleness_secs: u32,
+
+    /// PDA bump for OracleConfig
+    pub bump: u8,
+}
+
+impl OracleConfig {
+    pub const LEN: usize =
+        32 + // bot_meta
+        32 + // oracle
+        32 + // mint
+        2  + // max_deviation_bps
+        4  + // max_staleness_secs
+        1;   // bump
+
+    pub const BPS_DENOMINATOR: u128 = 10_000;
+}
+
+/* ======================================================
+ * OpenBook v2 open-orders accounts owned by the vault
+ * ====================================================== */
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub struct OpenOrdersEntry {
+    /// OpenBook v2 market
+    pub market: Pubkey,
+
+    /// Vault-owned OpenOrdersAccount on that market
+    pub open_orders: Pubkey,
+}
+
+#[account]
+pub struct OpenOrdersRecord {
+    /// BotMeta these open-orders accounts belong to
+    pub bot_meta: Pubkey,
+
+    /// One open-orders account per market (max MAX_ENTRIES)
+    pub entries: Vec<OpenOrdersEntry>,
+
+    /// PDA bump for OpenOrdersRecord
+    pub bump: u8,
+}
+
+impl OpenOrdersRecord {
+    pub const MAX_ENTRIES: usize = 16;
+
+    pub const LEN: usize =
+        32 + // bot_meta
+        4 + (32 + 32) * Self::MAX_ENTRIES + // entries
+        1;   // bump
+
+    /// Drop the entry for `market`, returning its open-orders account
+    pub fn remove(&mut self, market: &Pubkey) -> Option<Pubkey> {
+        let index = self.entries.iter().position(|entry| entry.market == *market)?;
+        Some(self.entries.swap_remove(index).open_orders)
+    }
+
+    /// Recorded open-orders account for `market`, if any
+    pub fn open_orders_for(&self, market: &Pubkey) -> Option<Pubkey> {
+        self.entries
+            .iter()
+            .find(|entry| entry.market == *market)
+            .map(|entry| entry.open_orders)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anchor_lang::Discriminator;
+
+    #[test]
+    fn settle_is_allowed_until_closed_whatever_the_pause_flags() {
+        for status in [
+            BotStatus::Active,
+            BotStatus::Paused,
+            BotStatus::Liquidating,
+            BotStatus::Closing,
+        ] {
+            status.check(BotAction::Settle).unwrap();
+        }
+        assert_eq!(
+            BotStatus::Closed.check(BotAction::Settle).unwrap_err(),
+            SolscopeError::BotClosed.into()
+        );
+
+        let mut data = vec![0u8; 8 + BotMeta::LEN];
+        data[..8].copy_from_slice(&BotMeta::DISCRIMINATOR);
+        let mut meta = BotMeta::try_deserialize(&mut &data[..]).unwrap();
+        meta.pause_flags = PAUSE_ALL;
+        meta.require_allows(BotAction::Settle).unwrap();
+        assert!(meta.require_allows(BotAction::Sell).is_err());
+    }
+
+    #[test]
+    fn open_orders_record_entries_can_be_removed() {
+        let entry = |market| OpenOrdersEntry {
+            market,
+            open_orders: Pubkey::new_unique(),
+        };
+        let (a, b) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let mut record = OpenOrdersRecord {
+            bot_meta: Pubkey::new_unique(),
+            entries: vec![entry(a), entry(b)],
+            bump: 255,
+        };
+        let b_open_orders = record.open_orders_for(&b);
+
+        assert!(record.remove(&a).is_some());
+        assert!(record.open_orders_for(&a).is_none());
+        assert_eq!(record.open_orders_for(&b), b_open_orders);
+        assert!(record.remove(&a).is_none());
+
+        assert!(record.remove(&b).is_some());
+        assert!(record.entries.is_empty());
+    }
 }
@@ -0,0 +1,54 @@
+// programs/solscope/src/test_utils.rs
+
+use anchor_lang::prelude::*;
+
+/// Owned backing storage for an AccountInfo in unit tests
+pub struct TestAccount {
+    pub key: Pubkey,
+    pub owner: Pubkey,
+    pub lamports: u64,
+    pub data: Vec<u8>,
+}
+
+impl TestAccount {
+    pub fn new(owner: Pubkey, data: Vec<u8>) -> Self {
+        Self::with_key(Pubkey::new_unique(), owner, data)
+    }
+
+    pub fn with_key(key: Pubkey, owner: Pubkey, data: Vec<u8>) -> Self {
+        Self {
+            key,
+            owner,
+            lamports: 1_000_000,
+            data,
+        }
+    }
+
+    pub fn info(&mut self) -> AccountInfo<'_> {
+        AccountInfo::new(
+            &self.key,
+            false,
+            true,
+            &mut self.lamports,
+            &mut self.data,
+            &self.owner,
+            false,
+            0,
+        )
+    }
+}
+
+/// Write `bytes` into `data` at `offset`
+pub fn put(data: &mut [u8], offset: usize, bytes: &[u8]) {
+    data[offset..offset + bytes.len()].copy_from_slice(bytes);
+}
+
+/// 165-byte SPL token account with the given mint / owner / amount (state Initialized)
+pub fn token_account_data(mint: &Pubkey, owner: &Pubkey, amount: u64) -> Vec<u8> {
+    let mut data = vec![0u8; 165];
+    put(&mut data, 0, mint.as_ref());
+    put(&mut data, 32, owner.as_ref());
+    put(&mut data, 64, &amount.to_le_bytes());
+    data[108] = 1;
+    data
+}
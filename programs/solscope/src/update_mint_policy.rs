@@ -0,0 +1,53 @@
+// programs/solscope/src/update_mint_policy.rs
+
+use anchor_lang::prelude::*;
+
+use crate::errors::SolscopeError;
+use crate::state::{BotMeta, MintPolicy, MintPolicyMode};
+
+#[derive(Accounts)]
+#[instruction(bot_id_hash: [u8; 32])]
+pub struct UpdateMintPolicy<'info> {
+    pub owner: Signer<'info>,
+
+    #[account(
+        seeds = [b"bot", owner.key().as_ref(), &bot_id_hash],
+        bump,
+        has_one = owner,
+    )]
+    pub bot_meta: Account<'info, BotMeta>,
+
+    #[account(
+        mut,
+        seeds = [b"mint_policy", bot_meta.key().as_ref()],
+        bump = mint_policy.bump,
+        has_one = bot_meta,
+    )]
+    pub mint_policy: Account<'info, MintPolicy>,
+}
+
+pub fn handler(
+    ctx: Context<UpdateMintPolicy>,
+    _bot_id_hash: [u8; 32],
+    mode: MintPolicyMode,
+    add: Vec<Pubkey>,
+    remove: Vec<Pubkey>,
+) -> Result<()> {
+    // owner-only enforced by has_one + owner signer
+    let mint_policy = &mut ctx.accounts.mint_policy;
+    mint_policy.mode = mode;
+
+    mint_policy.mints.retain(|mint| !remove.contains(mint));
+
+    for mint in add {
+        if !mint_policy.mints.contains(&mint) {
+            require!(
+                mint_policy.mints.len() < MintPolicy::MAX_MINTS,
+                SolscopeError::MintPolicyFull
+            );
+            mint_policy.mints.push(mint);
+        }
+    }
+
+    Ok(())
+}
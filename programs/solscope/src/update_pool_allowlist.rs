@@ -0,0 +1,51 @@
+// programs/solscope/src/update_pool_allowlist.rs
+
+use anchor_lang::prelude::*;
+
+use crate::errors::SolscopeError;
+use crate::state::{BotMeta, PoolAllowlist};
+
+#[derive(Accounts)]
+#[instruction(bot_id_hash: [u8; 32])]
+pub struct UpdatePoolAllowlist<'info> {
+    pub owner: Signer<'info>,
+
+    #[account(
+        seeds = [b"bot", owner.key().as_ref(), &bot_id_hash],
+        bump,
+        has_one = owner,
+    )]
+    pub bot_meta: Account<'info, BotMeta>,
+
+    #[account(
+        mut,
+        seeds = [b"pools", bot_meta.key().as_ref()],
+        bump = pool_allowlist.bump,
+        has_one = bot_meta,
+    )]
+    pub pool_allowlist: Account<'info, PoolAllowlist>,
+}
+
+pub fn handler(
+    ctx: Context<UpdatePoolAllowlist>,
+    _bot_id_hash: [u8; 32],
+    add: Vec<Pubkey>,
+    remove: Vec<Pubkey>,
+) -> Result<()> {
+    // owner-only enforced by has_one + owner signer
+    let pool_allowlist = &mut ctx.accounts.pool_allowlist;
+
+    pool_allowlist.amms.retain(|amm| !remove.contains(amm));
+
+    for amm in add {
+        if !pool_allowlist.amms.contains(&amm) {
+            require!(
+                pool_allowlist.amms.len() < PoolAllowlist::MAX_POOLS,
+                SolscopeError::PoolAllowlistFull
+            );
+            pool_allowlist.amms.push(amm);
+        }
+    }
+
+    Ok(())
+}
@@ -4,7 +4,7 @@ use anchor_lang::prelude::*;
 use anchor_lang::system_program;
 
 use crate::errors::SolscopeError;
-use crate::state::BotMeta;
+use crate::state::{BotAction, BotMeta};
 
 #[derive(Accounts)]
 #[instruction(bot_id_hash: [u8; 32])]
@@ -47,20 +47,43 @@ pub fn handler(
     amount: u64,
 ) -> Result<()> {
     require!(amount > 0, SolscopeError::InvalidAmount);
+    check_gates(&ctx, bot_id_hash)?;
 
+    let vault_lamports = ctx.accounts.vault.to_account_info().lamports();
+    require!(vault_lamports >= amount, SolscopeError::InsufficientVaultFunds);
+
+    // vault must end up either fully drained or still rent-exempt (no dust)
+    let remaining = vault_lamports - amount;
+    require!(
+        remaining == 0 || remaining >= Rent::get()?.minimum_balance(0),
+        SolscopeError::VaultBelowRentExempt
+    );
+
+    transfer_from_vault(&ctx, bot_id_hash, amount)
+}
+
+/// Withdraw everything the vault holds (computed on-chain at execution time)
+pub fn handler_all(ctx: Context<Withdraw>, bot_id_hash: [u8; 32]) -> Result<()> {
+    check_gates(&ctx, bot_id_hash)?;
+
+    // draining to zero is always allowed, so the maximal amount is the full balance
+    let amount = ctx.accounts.vault.to_account_info().lamports();
+    require!(amount > 0, SolscopeError::InsufficientVaultFunds);
+
+    transfer_from_vault(&ctx, bot_id_hash, amount)
+}
+
+fn check_gates(ctx: &Context<Withdraw>, bot_id_hash: [u8; 32]) -> Result<()> {
     // hard gates
     require!(
         ctx.accounts.bot_meta.bot_id_hash == bot_id_hash,
         SolscopeError::BotIdMismatch
     );
-    require!(
-        !ctx.accounts.bot_meta.paused,
-        SolscopeError::BotPaused
-    );
-
-    let vault_lamports = ctx.accounts.vault.to_account_info().lamports();
-    require!(vault_lamports >= amount, SolscopeError::InsufficientVaultFunds);
+    ctx.accounts.bot_meta.require_allows(BotAction::Withdraw)?;
+    Ok(())
+}
 
+fn transfer_from_vault(ctx: &Context<Withdraw>, bot_id_hash: [u8; 32], amount: u64) -> Result<()> {
     let seeds: &[&[u8]] = &[
         b"vault",
         ctx.accounts.owner.key.as_ref(),
@@ -81,4 +104,3 @@ pub fn handler(
     system_program::transfer(cpi_ctx, amount)?;
     Ok(())
 }
-//real one
\ No newline at end of file
@@ -0,0 +1,107 @@
+// programs/solscope/src/withdraw_token.rs
+
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::{self, Mint, Token, TokenAccount, TransferChecked},
+};
+
+use crate::errors::SolscopeError;
+use crate::state::{BotAction, BotMeta};
+
+#[derive(Accounts)]
+#[instruction(bot_id_hash: [u8; 32])]
+pub struct WithdrawToken<'info> {
+    /// Bot owner (must match BotMeta + vault derivation)
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    /// BotMeta PDA (must link owner + vault)
+    #[account(
+        seeds = [
+            b"bot",
+            owner.key().as_ref(),
+            &bot_id_hash,
+        ],
+        bump,
+        has_one = owner,
+        has_one = vault
+    )]
+    pub bot_meta: Account<'info, BotMeta>,
+
+    /// CHECK: Vault PDA (authority of the vault ATA)
+    #[account(
+        seeds = [
+            b"vault",
+            owner.key().as_ref(),
+            &bot_id_hash,
+        ],
+        bump
+    )]
+    pub vault: AccountInfo<'info>,
+
+    pub mint: Account<'info, Mint>,
+
+    /// Vault ATA for this mint (source)
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = vault
+    )]
+    pub vault_ata: Account<'info, TokenAccount>,
+
+    /// Owner ATA for this mint (destination)
+    #[account(
+        init_if_needed,
+        payer = owner,
+        associated_token::mint = mint,
+        associated_token::authority = owner
+    )]
+    pub owner_ata: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(
+    ctx: Context<WithdrawToken>,
+    bot_id_hash: [u8; 32],
+    amount: u64,
+) -> Result<()> {
+    require!(amount > 0, SolscopeError::InvalidAmount);
+
+    // hard gates
+    require!(
+        ctx.accounts.bot_meta.bot_id_hash == bot_id_hash,
+        SolscopeError::BotIdMismatch
+    );
+    ctx.accounts.bot_meta.require_allows(BotAction::Withdraw)?;
+
+    require!(
+        ctx.accounts.vault_ata.amount >= amount,
+        SolscopeError::InsufficientVaultFunds
+    );
+
+    let seeds: &[&[u8]] = &[
+        b"vault",
+        ctx.accounts.owner.key.as_ref(),
+        &bot_id_hash,
+        &[ctx.bumps.vault],
+    ];
+    let signer_seeds = &[seeds];
+
+    let cpi_ctx = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        TransferChecked {
+            from: ctx.accounts.vault_ata.to_account_info(),
+            mint: ctx.accounts.mint.to_account_info(),
+            to: ctx.accounts.owner_ata.to_account_info(),
+            authority: ctx.accounts.vault.to_account_info(),
+        },
+        signer_seeds,
+    );
+
+    token::transfer_checked(cpi_ctx, amount, ctx.accounts.mint.decimals)?;
+    Ok(())
+}